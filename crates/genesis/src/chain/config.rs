@@ -121,6 +121,15 @@ impl ChainConfig {
             .unwrap_or_else(|| base_fee_params_canyon(self.chain_id))
     }
 
+    /// Inherits the unset hardfork activation times from the superchain-wide `defaults`, if the
+    /// chain declares a [ChainConfig::superchain_time]. See [HardForkConfiguration::inherit].
+    pub fn inherit_superchain_hardforks(&mut self, defaults: &HardForkConfiguration) {
+        if let Some(superchain_time) = self.superchain_time {
+            self.hardfork_configuration =
+                self.hardfork_configuration.inherit(defaults, superchain_time);
+        }
+    }
+
     /// Loads the rollup config for the OP-Stack chain given the chain config and address list.
     #[deprecated(since = "0.2.1", note = "please use `as_rollup_config` instead")]
     pub fn load_op_stack_rollup_config(&self) -> RollupConfig {
@@ -169,6 +178,7 @@ impl ChainConfig {
                 .alt_da
                 .as_ref()
                 .and_then(|alt_da| alt_da.da_challenge_address),
+            alt_da: self.alt_da.clone(),
 
             // The below chain parameters can be different per OP-Stack chain,
             // but since none of the superchain chains differ, it's not represented in the
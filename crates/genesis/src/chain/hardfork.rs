@@ -24,3 +24,160 @@ pub struct HardForkConfiguration {
     /// Interop hardfork activation time
     pub interop_time: Option<u64>,
 }
+
+impl HardForkConfiguration {
+    /// Returns the configuration with its unset activation times inherited from the
+    /// superchain-wide `defaults`, for a chain that joined the superchain at `superchain_time`.
+    ///
+    /// Following the superchain-registry rules, a default activating at or after the superchain
+    /// time is inherited as is, while a default activating before it is set to zero, since the
+    /// chain must already run that hardfork to join the superchain.
+    pub fn inherit(&self, defaults: &Self, superchain_time: u64) -> Self {
+        let inherit = |time: Option<u64>, default: Option<u64>| {
+            time.or_else(|| default.map(|t| if t >= superchain_time { t } else { 0 }))
+        };
+        Self {
+            canyon_time: inherit(self.canyon_time, defaults.canyon_time),
+            delta_time: inherit(self.delta_time, defaults.delta_time),
+            ecotone_time: inherit(self.ecotone_time, defaults.ecotone_time),
+            fjord_time: inherit(self.fjord_time, defaults.fjord_time),
+            granite_time: inherit(self.granite_time, defaults.granite_time),
+            holocene_time: inherit(self.holocene_time, defaults.holocene_time),
+            isthmus_time: inherit(self.isthmus_time, defaults.isthmus_time),
+            interop_time: inherit(self.interop_time, defaults.interop_time),
+        }
+    }
+}
+
+/// An OP Stack hardfork.
+///
+/// Variants are ordered by activation, so later hardforks compare greater than earlier ones.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum OpHardfork {
+    /// The Bedrock hardfork, active at genesis of every OP Stack chain.
+    Bedrock,
+    /// The Regolith hardfork.
+    Regolith,
+    /// The Canyon hardfork.
+    Canyon,
+    /// The Delta hardfork.
+    Delta,
+    /// The Ecotone hardfork.
+    Ecotone,
+    /// The Fjord hardfork.
+    Fjord,
+    /// The Granite hardfork.
+    Granite,
+    /// The Holocene hardfork.
+    Holocene,
+    /// The Isthmus hardfork.
+    Isthmus,
+    /// The Interop hardfork.
+    Interop,
+}
+
+impl OpHardfork {
+    /// All hardforks, in activation order.
+    pub const ALL: [Self; 10] = [
+        Self::Bedrock,
+        Self::Regolith,
+        Self::Canyon,
+        Self::Delta,
+        Self::Ecotone,
+        Self::Fjord,
+        Self::Granite,
+        Self::Holocene,
+        Self::Isthmus,
+        Self::Interop,
+    ];
+
+    /// Returns the name of the hardfork.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Bedrock => "Bedrock",
+            Self::Regolith => "Regolith",
+            Self::Canyon => "Canyon",
+            Self::Delta => "Delta",
+            Self::Ecotone => "Ecotone",
+            Self::Fjord => "Fjord",
+            Self::Granite => "Granite",
+            Self::Holocene => "Holocene",
+            Self::Isthmus => "Isthmus",
+            Self::Interop => "Interop",
+        }
+    }
+}
+
+impl core::fmt::Display for OpHardfork {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_hardfork_configuration_inherit() {
+        let chain = HardForkConfiguration { canyon_time: Some(5), ..Default::default() };
+        let defaults = HardForkConfiguration {
+            canyon_time: Some(10),
+            delta_time: Some(20),
+            ecotone_time: Some(30),
+            fjord_time: Some(40),
+            ..Default::default()
+        };
+        assert_eq!(
+            chain.inherit(&defaults, 25),
+            HardForkConfiguration {
+                canyon_time: Some(5),
+                delta_time: Some(0),
+                ecotone_time: Some(30),
+                fjord_time: Some(40),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_hardfork_configuration_inherit_at_superchain_time() {
+        // A default activating at the superchain time is kept.
+        let defaults = HardForkConfiguration {
+            delta_time: Some(20),
+            ecotone_time: Some(30),
+            ..Default::default()
+        };
+        assert_eq!(
+            HardForkConfiguration::default().inherit(&defaults, 30),
+            HardForkConfiguration {
+                delta_time: Some(0),
+                ecotone_time: Some(30),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_hardfork_order() {
+        assert!(OpHardfork::ALL.windows(2).all(|w| w[0] < w[1]));
+        assert!(OpHardfork::Bedrock < OpHardfork::Interop);
+    }
+
+    #[test]
+    fn test_hardfork_display() {
+        assert_eq!(OpHardfork::Holocene.to_string(), "Holocene");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_hardfork_serde() {
+        let fork: OpHardfork = serde_json::from_str("\"isthmus\"").unwrap();
+        assert_eq!(fork, OpHardfork::Isthmus);
+        assert_eq!(serde_json::to_string(&OpHardfork::Fjord).unwrap(), "\"fjord\"");
+    }
+}
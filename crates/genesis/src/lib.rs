@@ -35,6 +35,10 @@ pub use updates::{
 };
 
 mod system;
+#[cfg(feature = "provider")]
+pub use system::{
+    AlloySystemConfigProvider, ISystemConfig, SystemConfigProvider, SystemConfigProviderError,
+};
 pub use system::{
     BatcherUpdateError, EIP1559UpdateError, GasConfigUpdateError, GasLimitUpdateError,
     LogProcessingError, OperatorFeeUpdateError, SystemConfig, SystemConfigLog, SystemConfigUpdate,
@@ -44,8 +48,8 @@ pub use system::{
 
 mod chain;
 pub use chain::{
-    AddressList, AltDAConfig, ChainConfig, HardForkConfiguration, Roles, BASE_MAINNET_CHAIN_ID,
-    BASE_SEPOLIA_CHAIN_ID, OP_MAINNET_CHAIN_ID, OP_SEPOLIA_CHAIN_ID,
+    AddressList, AltDAConfig, ChainConfig, HardForkConfiguration, OpHardfork, Roles,
+    BASE_MAINNET_CHAIN_ID, BASE_SEPOLIA_CHAIN_ID, OP_MAINNET_CHAIN_ID, OP_SEPOLIA_CHAIN_ID,
 };
 
 mod genesis;
@@ -53,6 +57,7 @@ pub use genesis::ChainGenesis;
 
 mod rollup;
 pub use rollup::{
-    RollupConfig, DEFAULT_INTEROP_MESSAGE_EXPIRY_WINDOW, FJORD_MAX_SEQUENCER_DRIFT,
-    GRANITE_CHANNEL_TIMEOUT, MAX_RLP_BYTES_PER_CHANNEL_BEDROCK, MAX_RLP_BYTES_PER_CHANNEL_FJORD,
+    RollupConfig, RollupConfigError, DEFAULT_INTEROP_MESSAGE_EXPIRY_WINDOW,
+    FJORD_MAX_SEQUENCER_DRIFT, GRANITE_CHANNEL_TIMEOUT, MAX_RLP_BYTES_PER_CHANNEL_BEDROCK,
+    MAX_RLP_BYTES_PER_CHANNEL_FJORD,
 };
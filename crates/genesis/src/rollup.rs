@@ -4,9 +4,10 @@ use alloy_eips::eip1559::BaseFeeParams;
 use alloy_primitives::Address;
 
 use crate::{
-    base_fee_params, base_fee_params_canyon, ChainGenesis, HardForkConfiguration,
-    OP_MAINNET_CHAIN_ID,
+    base_fee_params, base_fee_params_canyon, AltDAConfig, ChainGenesis, HardForkConfiguration,
+    OpHardfork, OP_MAINNET_CHAIN_ID,
 };
+use alloc::vec::Vec;
 
 /// The max rlp bytes per channel for the Bedrock hardfork.
 pub const MAX_RLP_BYTES_PER_CHANNEL_BEDROCK: u64 = 10_000_000;
@@ -33,6 +34,60 @@ const fn default_interop_message_expiry_window() -> u64 {
     DEFAULT_INTEROP_MESSAGE_EXPIRY_WINDOW
 }
 
+/// An error in a [RollupConfig], returned by [RollupConfig::validate].
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+pub enum RollupConfigError {
+    /// The block time is zero.
+    #[error("Block time cannot be 0")]
+    ZeroBlockTime,
+    /// The sequencing window size is zero.
+    #[error("Sequencing window size cannot be 0")]
+    ZeroSeqWindowSize,
+    /// The channel timeout is zero.
+    #[error("Channel timeout cannot be 0")]
+    ZeroChannelTimeout,
+    /// The genesis L2 time is not a multiple of the block time.
+    #[error("Genesis L2 time {l2_time} is not a multiple of the block time {block_time}")]
+    UnalignedGenesisTime {
+        /// The genesis L2 time.
+        l2_time: u64,
+        /// The block time.
+        block_time: u64,
+    },
+    /// The L1 chain ID is zero.
+    #[error("L1 chain ID cannot be 0")]
+    ZeroL1ChainId,
+    /// The L2 chain ID is zero.
+    #[error("L2 chain ID cannot be 0")]
+    ZeroL2ChainId,
+    /// The L1 and L2 chain IDs are the same.
+    #[error("L1 and L2 chain IDs must be different, both are {0}")]
+    SameL1L2ChainId(u64),
+    /// A hardfork is scheduled while the hardfork preceding it is not.
+    #[error("Hardfork {fork} is scheduled, but prior hardfork {prior} is not")]
+    MissingPriorHardfork {
+        /// The scheduled hardfork.
+        fork: OpHardfork,
+        /// The unscheduled prior hardfork.
+        prior: OpHardfork,
+    },
+    /// A hardfork activates before the hardfork preceding it.
+    #[error("Hardfork {fork} activates at {time}, before prior hardfork {prior} at {prior_time}")]
+    HardforkOutOfOrder {
+        /// The hardfork activating too early.
+        fork: OpHardfork,
+        /// Its activation time.
+        time: u64,
+        /// The prior hardfork.
+        prior: OpHardfork,
+        /// The activation time of the prior hardfork.
+        prior_time: u64,
+    },
+    /// Alt-DA settings are present without a non-zero DA challenge contract address.
+    #[error("Alt-DA settings are present without a DA challenge address")]
+    MissingDaChallengeAddress,
+}
+
 /// The Rollup configuration.
 #[derive(Debug, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -135,6 +190,9 @@ pub struct RollupConfig {
     /// stored at.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub da_challenge_address: Option<Address>,
+    /// The alt-DA settings of the rollup, if any.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub alt_da: Option<AltDAConfig>,
     /// `interop_message_expiry_window` is the maximum time (in seconds) that an initiating message
     /// can be referenced on a remote chain before it expires.
     #[cfg_attr(feature = "serde", serde(default = "default_interop_message_expiry_window"))]
@@ -182,6 +240,7 @@ impl<'a> arbitrary::Arbitrary<'a> for RollupConfig {
             superchain_config_address: Option::<Address>::arbitrary(u)?,
             blobs_enabled_l1_timestamp: Option::<u64>::arbitrary(u)?,
             da_challenge_address: Option::<Address>::arbitrary(u)?,
+            alt_da: Option::<AltDAConfig>::arbitrary(u)?,
             interop_message_expiry_window: u.arbitrary()?,
         })
     }
@@ -217,6 +276,7 @@ impl Default for RollupConfig {
             superchain_config_address: None,
             blobs_enabled_l1_timestamp: None,
             da_challenge_address: None,
+            alt_da: None,
             interop_message_expiry_window: DEFAULT_INTEROP_MESSAGE_EXPIRY_WINDOW,
         }
     }
@@ -232,20 +292,14 @@ impl RollupConfig {
     /// ## Returns
     /// The active [`revm::primitives::SpecId`] for the executor.
     pub fn spec_id(&self, timestamp: u64) -> revm::primitives::SpecId {
-        if self.is_isthmus_active(timestamp) {
-            revm::primitives::SpecId::ISTHMUS
-        } else if self.is_holocene_active(timestamp) {
-            revm::primitives::SpecId::HOLOCENE
-        } else if self.is_fjord_active(timestamp) {
-            revm::primitives::SpecId::FJORD
-        } else if self.is_ecotone_active(timestamp) {
-            revm::primitives::SpecId::ECOTONE
-        } else if self.is_canyon_active(timestamp) {
-            revm::primitives::SpecId::CANYON
-        } else if self.is_regolith_active(timestamp) {
-            revm::primitives::SpecId::REGOLITH
-        } else {
-            revm::primitives::SpecId::BEDROCK
+        match self.active_fork(timestamp) {
+            OpHardfork::Isthmus | OpHardfork::Interop => revm::primitives::SpecId::ISTHMUS,
+            OpHardfork::Holocene => revm::primitives::SpecId::HOLOCENE,
+            OpHardfork::Fjord | OpHardfork::Granite => revm::primitives::SpecId::FJORD,
+            OpHardfork::Ecotone => revm::primitives::SpecId::ECOTONE,
+            OpHardfork::Canyon | OpHardfork::Delta => revm::primitives::SpecId::CANYON,
+            OpHardfork::Regolith => revm::primitives::SpecId::REGOLITH,
+            OpHardfork::Bedrock => revm::primitives::SpecId::BEDROCK,
         }
     }
 }
@@ -296,6 +350,155 @@ impl RollupConfig {
         self.interop_time.is_some_and(|t| timestamp >= t)
     }
 
+    /// Returns true if the given hardfork is active at the given timestamp.
+    pub fn is_fork_active(&self, fork: OpHardfork, timestamp: u64) -> bool {
+        match fork {
+            OpHardfork::Bedrock => true,
+            OpHardfork::Regolith => self.is_regolith_active(timestamp),
+            OpHardfork::Canyon => self.is_canyon_active(timestamp),
+            OpHardfork::Delta => self.is_delta_active(timestamp),
+            OpHardfork::Ecotone => self.is_ecotone_active(timestamp),
+            OpHardfork::Fjord => self.is_fjord_active(timestamp),
+            OpHardfork::Granite => self.is_granite_active(timestamp),
+            OpHardfork::Holocene => self.is_holocene_active(timestamp),
+            OpHardfork::Isthmus => self.is_isthmus_active(timestamp),
+            OpHardfork::Interop => self.is_interop_active(timestamp),
+        }
+    }
+
+    /// Returns the latest hardfork active at the given timestamp.
+    pub fn active_fork(&self, timestamp: u64) -> OpHardfork {
+        OpHardfork::ALL
+            .into_iter()
+            .rev()
+            .find(|fork| self.is_fork_active(*fork, timestamp))
+            .unwrap_or(OpHardfork::Bedrock)
+    }
+
+    /// Returns true if the block at the given timestamp is the first L2 block in which the given
+    /// hardfork is active.
+    ///
+    /// A fork active at genesis has no activation block.
+    pub fn is_first_fork_block(&self, fork: OpHardfork, timestamp: u64) -> bool {
+        timestamp >= self.block_time
+            && self.is_fork_activation(fork, timestamp - self.block_time, timestamp)
+    }
+
+    /// Returns true if the given hardfork activates between the parent block at
+    /// `parent_timestamp` and its child block at `timestamp`, i.e. it is active in the child
+    /// block but not in its parent.
+    pub fn is_fork_activation(
+        &self,
+        fork: OpHardfork,
+        parent_timestamp: u64,
+        timestamp: u64,
+    ) -> bool {
+        self.is_fork_active(fork, timestamp) && !self.is_fork_active(fork, parent_timestamp)
+    }
+
+    /// Returns the first hardfork scheduled strictly after the given timestamp, along with its
+    /// activation time.
+    pub fn next_fork_after(&self, timestamp: u64) -> Option<(OpHardfork, u64)> {
+        self.fork_schedule().find(|(_, time)| *time > timestamp)
+    }
+
+    /// Returns an iterator over the scheduled hardforks and their activation times, in hardfork
+    /// order. Bedrock is scheduled at the L2 genesis.
+    ///
+    /// The activation times are those returned by [Self::activation_time], so they never
+    /// decrease along the schedule.
+    pub fn fork_schedule(&self) -> impl Iterator<Item = (OpHardfork, u64)> + '_ {
+        OpHardfork::ALL.into_iter().filter_map(|fork| Some((fork, self.activation_time(fork)?)))
+    }
+
+    /// Returns the time from which the given hardfork is active, if it is scheduled.
+    ///
+    /// A hardfork is active once any later hardfork is, so this is the earliest time configured
+    /// for the hardfork or a later one. Hardforks configured before the L2 genesis are active
+    /// from the genesis.
+    pub fn activation_time(&self, fork: OpHardfork) -> Option<u64> {
+        OpHardfork::ALL
+            .into_iter()
+            .filter(|f| *f >= fork)
+            .filter_map(|f| self.configured_time(f))
+            .min()
+            .map(|time| time.max(self.genesis.l2_time))
+    }
+
+    /// Returns the activation time configured for the given hardfork, if any.
+    ///
+    /// Bedrock is active from the L2 genesis.
+    const fn configured_time(&self, fork: OpHardfork) -> Option<u64> {
+        match fork {
+            OpHardfork::Bedrock => Some(self.genesis.l2_time),
+            OpHardfork::Regolith => self.regolith_time,
+            OpHardfork::Canyon => self.canyon_time,
+            OpHardfork::Delta => self.delta_time,
+            OpHardfork::Ecotone => self.ecotone_time,
+            OpHardfork::Fjord => self.fjord_time,
+            OpHardfork::Granite => self.granite_time,
+            OpHardfork::Holocene => self.holocene_time,
+            OpHardfork::Isthmus => self.isthmus_time,
+            OpHardfork::Interop => self.interop_time,
+        }
+    }
+
+    /// Validates the rollup config, returning every [RollupConfigError] found.
+    pub fn validate(&self) -> Result<(), Vec<RollupConfigError>> {
+        let mut errors = Vec::new();
+
+        if self.block_time == 0 {
+            errors.push(RollupConfigError::ZeroBlockTime);
+        } else if self.genesis.l2_time % self.block_time != 0 {
+            errors.push(RollupConfigError::UnalignedGenesisTime {
+                l2_time: self.genesis.l2_time,
+                block_time: self.block_time,
+            });
+        }
+        if self.seq_window_size == 0 {
+            errors.push(RollupConfigError::ZeroSeqWindowSize);
+        }
+        if self.channel_timeout == 0 {
+            errors.push(RollupConfigError::ZeroChannelTimeout);
+        }
+
+        if self.l1_chain_id == 0 {
+            errors.push(RollupConfigError::ZeroL1ChainId);
+        }
+        if self.l2_chain_id == 0 {
+            errors.push(RollupConfigError::ZeroL2ChainId);
+        }
+        if self.l1_chain_id != 0 && self.l1_chain_id == self.l2_chain_id {
+            errors.push(RollupConfigError::SameL1L2ChainId(self.l1_chain_id));
+        }
+
+        // Bedrock activates at genesis, so the ordering starts from Regolith.
+        for pair in OpHardfork::ALL[1..].windows(2) {
+            let (prior, fork) = (pair[0], pair[1]);
+            match (self.configured_time(prior), self.configured_time(fork)) {
+                (None, Some(_)) => {
+                    errors.push(RollupConfigError::MissingPriorHardfork { fork, prior })
+                }
+                (Some(prior_time), Some(time)) if time < prior_time => errors
+                    .push(RollupConfigError::HardforkOutOfOrder { fork, time, prior, prior_time }),
+                _ => {}
+            }
+        }
+
+        if let Some(alt_da) = &self.alt_da {
+            let is_set = |addr: Option<Address>| addr.is_some_and(|addr| !addr.is_zero());
+            if !is_set(self.da_challenge_address) && !is_set(alt_da.da_challenge_address) {
+                errors.push(RollupConfigError::MissingDaChallengeAddress);
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
     /// Returns true if a DA Challenge proxy Address is provided in the rollup config and the
     /// address is not zero.
     pub fn is_alt_da_enabled(&self) -> bool {
@@ -372,6 +575,7 @@ mod tests {
     use super::*;
     #[cfg(feature = "serde")]
     use crate::{SystemConfig, OP_MAINNET_BASE_FEE_PARAMS, OP_MAINNET_BASE_FEE_PARAMS_CANYON};
+    use alloc::vec;
     #[cfg(feature = "serde")]
     use alloy_eips::BlockNumHash;
     use alloy_primitives::address;
@@ -524,6 +728,186 @@ mod tests {
         assert!(!config.is_interop_active(9));
     }
 
+    #[test]
+    fn test_fork_active() {
+        let config = RollupConfig { ecotone_time: Some(10), ..Default::default() };
+        assert!(config.is_fork_active(OpHardfork::Bedrock, 0));
+        assert!(!config.is_fork_active(OpHardfork::Canyon, 9));
+        assert!(config.is_fork_active(OpHardfork::Canyon, 10));
+        assert!(config.is_fork_active(OpHardfork::Ecotone, 10));
+        assert!(!config.is_fork_active(OpHardfork::Fjord, 10));
+    }
+
+    #[test]
+    fn test_activation_time() {
+        let mut config = RollupConfig { ecotone_time: Some(10), ..Default::default() };
+        config.genesis.l2_time = 5;
+        assert_eq!(config.activation_time(OpHardfork::Bedrock), Some(5));
+        assert_eq!(config.activation_time(OpHardfork::Ecotone), Some(10));
+        assert_eq!(config.activation_time(OpHardfork::Fjord), None);
+
+        // Hardforks are implied by later hardforks.
+        assert_eq!(config.activation_time(OpHardfork::Regolith), Some(10));
+        config.canyon_time = Some(20);
+        assert_eq!(config.activation_time(OpHardfork::Canyon), Some(10));
+
+        // Hardforks configured before the genesis activate at the genesis.
+        config.regolith_time = Some(0);
+        assert_eq!(config.activation_time(OpHardfork::Regolith), Some(5));
+
+        // The effective activation times agree with the activation checks.
+        for fork in OpHardfork::ALL {
+            let time = config.activation_time(fork);
+            for timestamp in 5..25 {
+                assert_eq!(
+                    config.is_fork_active(fork, timestamp),
+                    time.is_some_and(|t| timestamp >= t),
+                    "{fork} at {timestamp}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_active_fork() {
+        let config = RollupConfig {
+            regolith_time: Some(0),
+            canyon_time: Some(10),
+            ecotone_time: Some(20),
+            ..Default::default()
+        };
+        assert_eq!(config.active_fork(0), OpHardfork::Regolith);
+        assert_eq!(config.active_fork(9), OpHardfork::Regolith);
+        assert_eq!(config.active_fork(10), OpHardfork::Canyon);
+        assert_eq!(config.active_fork(u64::MAX), OpHardfork::Ecotone);
+        assert_eq!(RollupConfig::default().active_fork(0), OpHardfork::Bedrock);
+    }
+
+    #[test]
+    fn test_fork_schedule() {
+        let config = RollupConfig {
+            genesis: ChainGenesis { l2_time: 5, ..Default::default() },
+            regolith_time: Some(0),
+            canyon_time: Some(10),
+            ecotone_time: Some(20),
+            holocene_time: Some(30),
+            ..Default::default()
+        };
+        assert_eq!(
+            config.fork_schedule().collect::<Vec<_>>(),
+            vec![
+                (OpHardfork::Bedrock, 5),
+                (OpHardfork::Regolith, 5),
+                (OpHardfork::Canyon, 10),
+                (OpHardfork::Delta, 20),
+                (OpHardfork::Ecotone, 20),
+                (OpHardfork::Fjord, 30),
+                (OpHardfork::Granite, 30),
+                (OpHardfork::Holocene, 30),
+            ]
+        );
+        assert_eq!(config.next_fork_after(5), Some((OpHardfork::Canyon, 10)));
+        assert_eq!(config.next_fork_after(10), Some((OpHardfork::Delta, 20)));
+        assert_eq!(config.next_fork_after(20), Some((OpHardfork::Fjord, 30)));
+        assert_eq!(config.next_fork_after(30), None);
+    }
+
+    #[test]
+    fn test_is_first_fork_block() {
+        let config = RollupConfig {
+            block_time: 2,
+            regolith_time: Some(0),
+            holocene_time: Some(10),
+            isthmus_time: Some(13),
+            ..Default::default()
+        };
+        assert!(!config.is_first_fork_block(OpHardfork::Regolith, 0));
+        assert!(!config.is_first_fork_block(OpHardfork::Holocene, 8));
+        assert!(config.is_first_fork_block(OpHardfork::Holocene, 10));
+        assert!(!config.is_first_fork_block(OpHardfork::Holocene, 12));
+        // Unaligned activation times activate in the first block past them.
+        assert!(config.is_first_fork_block(OpHardfork::Isthmus, 14));
+        assert!(!config.is_first_fork_block(OpHardfork::Isthmus, 16));
+
+        assert!(config.is_fork_activation(OpHardfork::Holocene, 8, 10));
+        assert!(!config.is_fork_activation(OpHardfork::Holocene, 10, 12));
+        assert!(!config.is_fork_activation(OpHardfork::Regolith, 0, 0));
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut config = RollupConfig {
+            genesis: ChainGenesis { l2_time: 10, ..Default::default() },
+            block_time: 2,
+            seq_window_size: 3600,
+            channel_timeout: 300,
+            l1_chain_id: 1,
+            l2_chain_id: 10,
+            regolith_time: Some(0),
+            canyon_time: Some(10),
+            ..Default::default()
+        };
+        assert_eq!(config.validate(), Ok(()));
+
+        config.alt_da = Some(AltDAConfig::default());
+        assert_eq!(config.validate(), Err(vec![RollupConfigError::MissingDaChallengeAddress]));
+        config.da_challenge_address = Some(Address::ZERO);
+        assert_eq!(config.validate(), Err(vec![RollupConfigError::MissingDaChallengeAddress]));
+        config.alt_da =
+            Some(AltDAConfig { da_challenge_address: Some(Address::ZERO), ..Default::default() });
+        assert_eq!(config.validate(), Err(vec![RollupConfigError::MissingDaChallengeAddress]));
+        config.da_challenge_address = Some(Address::with_last_byte(1));
+        assert_eq!(config.validate(), Ok(()));
+        config.da_challenge_address = None;
+        config.alt_da = Some(AltDAConfig {
+            da_challenge_address: Some(Address::with_last_byte(1)),
+            ..Default::default()
+        });
+        assert_eq!(config.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_collects_errors() {
+        let config = RollupConfig {
+            genesis: ChainGenesis { l2_time: 1, ..Default::default() },
+            block_time: 2,
+            l1_chain_id: 1,
+            l2_chain_id: 1,
+            regolith_time: Some(10),
+            canyon_time: Some(5),
+            ecotone_time: Some(20),
+            ..Default::default()
+        };
+        assert_eq!(
+            config.validate(),
+            Err(vec![
+                RollupConfigError::UnalignedGenesisTime { l2_time: 1, block_time: 2 },
+                RollupConfigError::ZeroSeqWindowSize,
+                RollupConfigError::ZeroChannelTimeout,
+                RollupConfigError::SameL1L2ChainId(1),
+                RollupConfigError::HardforkOutOfOrder {
+                    fork: OpHardfork::Canyon,
+                    time: 5,
+                    prior: OpHardfork::Regolith,
+                    prior_time: 10,
+                },
+                RollupConfigError::MissingPriorHardfork {
+                    fork: OpHardfork::Ecotone,
+                    prior: OpHardfork::Delta,
+                },
+            ])
+        );
+        assert_eq!(
+            RollupConfig::default().validate().unwrap_err()[..4],
+            [
+                RollupConfigError::ZeroBlockTime,
+                RollupConfigError::ZeroSeqWindowSize,
+                RollupConfigError::ZeroChannelTimeout,
+                RollupConfigError::ZeroL1ChainId,
+            ]
+        );
+    }
+
     #[test]
     fn test_alt_da_enabled() {
         let mut config = RollupConfig::default();
@@ -4,7 +4,7 @@ use alloy_consensus::{Eip658Value, Receipt};
 use alloy_primitives::{Address, Log, B64, U256};
 
 use crate::{
-    RollupConfig, SystemConfigLog, SystemConfigUpdateError, SystemConfigUpdateKind,
+    OpHardfork, RollupConfig, SystemConfigLog, SystemConfigUpdateError, SystemConfigUpdateKind,
     CONFIG_UPDATE_TOPIC,
 };
 
@@ -77,7 +77,8 @@ impl SystemConfig {
         // For the first holocene block, a zero'd out B64 is returned to signal the
         // execution layer to use the canyon base fee parameters. Else, the system
         // config's eip1559 parameters are encoded as a B64.
-        if is_holocene && !rollup_config.is_holocene_active(parent_timestamp) {
+        if rollup_config.is_fork_activation(OpHardfork::Holocene, parent_timestamp, next_timestamp)
+        {
             Some(B64::ZERO)
         } else {
             is_holocene.then_some(B64::from_slice(
@@ -26,3 +26,10 @@ pub use errors::{
     BatcherUpdateError, EIP1559UpdateError, GasConfigUpdateError, GasLimitUpdateError,
     LogProcessingError, OperatorFeeUpdateError, SystemConfigUpdateError,
 };
+
+#[cfg(feature = "provider")]
+mod provider;
+#[cfg(feature = "provider")]
+pub use provider::{
+    AlloySystemConfigProvider, ISystemConfig, SystemConfigProvider, SystemConfigProviderError,
+};
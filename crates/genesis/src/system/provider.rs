@@ -0,0 +1,259 @@
+//! Contains the [SystemConfigProvider] trait, reading the [SystemConfig] from the L1
+//! `SystemConfig` contract.
+
+use crate::{OpHardfork, SystemConfig};
+use alloc::boxed::Box;
+use alloy_primitives::{Address, Bytes};
+use alloy_sol_types::{sol, SolCall};
+use async_trait::async_trait;
+
+sol! {
+    /// The getters of the L1 `SystemConfig` contract.
+    interface ISystemConfig {
+        function batcherHash() external view returns (bytes32);
+        function overhead() external view returns (uint256);
+        function scalar() external view returns (uint256);
+        function gasLimit() external view returns (uint64);
+        function basefeeScalar() external view returns (uint32);
+        function blobbasefeeScalar() external view returns (uint32);
+        function eip1559Denominator() external view returns (uint32);
+        function eip1559Elasticity() external view returns (uint32);
+        function operatorFeeScalar() external view returns (uint32);
+        function operatorFeeConstant() external view returns (uint64);
+    }
+}
+
+/// An error reading the [SystemConfig] from the L1 `SystemConfig` contract.
+#[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
+pub enum SystemConfigProviderError<E> {
+    /// The call to the contract failed.
+    #[error("SystemConfig call {method} failed: {error}")]
+    Call {
+        /// The signature of the called method.
+        method: &'static str,
+        /// The provider error.
+        error: E,
+    },
+    /// The return data of the call could not be decoded.
+    #[error("Failed to decode the return data of SystemConfig call {0}")]
+    Decode(&'static str),
+}
+
+/// A provider of the L1 `SystemConfig` contract state.
+///
+/// Implementors only need to execute an `eth_call` at a given L1 block, the [SystemConfig] is
+/// read on top of it by [SystemConfigProvider::system_config].
+#[async_trait]
+pub trait SystemConfigProvider: Send + Sync {
+    /// The error returned by the provider.
+    type Error: core::fmt::Display + Send + Sync;
+
+    /// Executes a call of the given input against the `to` contract, at the given L1 block.
+    async fn call(
+        &self,
+        to: Address,
+        input: Bytes,
+        block_number: u64,
+    ) -> Result<Bytes, Self::Error>;
+
+    /// Reads the [SystemConfig] from the L1 `SystemConfig` contract at the given address and L1
+    /// block.
+    ///
+    /// Only the values defined by the given hardfork are read, the getters of later hardforks
+    /// not existing on older contract versions.
+    async fn system_config(
+        &self,
+        address: Address,
+        block_number: u64,
+        fork: OpHardfork,
+    ) -> Result<SystemConfig, SystemConfigProviderError<Self::Error>> {
+        let batcher_hash =
+            read(self, address, block_number, ISystemConfig::batcherHashCall {}).await?._0;
+        let mut config = SystemConfig {
+            batcher_address: Address::from_word(batcher_hash),
+            overhead: read(self, address, block_number, ISystemConfig::overheadCall {}).await?._0,
+            scalar: read(self, address, block_number, ISystemConfig::scalarCall {}).await?._0,
+            gas_limit: read(self, address, block_number, ISystemConfig::gasLimitCall {}).await?._0,
+            ..Default::default()
+        };
+
+        if fork >= OpHardfork::Ecotone {
+            let call = ISystemConfig::basefeeScalarCall {};
+            config.base_fee_scalar = Some(read(self, address, block_number, call).await?._0 as u64);
+            let call = ISystemConfig::blobbasefeeScalarCall {};
+            config.blob_base_fee_scalar =
+                Some(read(self, address, block_number, call).await?._0 as u64);
+        }
+        if fork >= OpHardfork::Holocene {
+            let call = ISystemConfig::eip1559DenominatorCall {};
+            config.eip1559_denominator = Some(read(self, address, block_number, call).await?._0);
+            let call = ISystemConfig::eip1559ElasticityCall {};
+            config.eip1559_elasticity = Some(read(self, address, block_number, call).await?._0);
+        }
+        if fork >= OpHardfork::Isthmus {
+            let call = ISystemConfig::operatorFeeScalarCall {};
+            config.operator_fee_scalar = Some(read(self, address, block_number, call).await?._0);
+            let call = ISystemConfig::operatorFeeConstantCall {};
+            config.operator_fee_constant = Some(read(self, address, block_number, call).await?._0);
+        }
+
+        Ok(config)
+    }
+}
+
+/// Executes the given contract call through the provider and decodes its return data.
+async fn read<P, C>(
+    provider: &P,
+    address: Address,
+    block_number: u64,
+    call: C,
+) -> Result<C::Return, SystemConfigProviderError<P::Error>>
+where
+    P: SystemConfigProvider + ?Sized,
+    C: SolCall,
+{
+    let output = provider
+        .call(address, call.abi_encode().into(), block_number)
+        .await
+        .map_err(|error| SystemConfigProviderError::Call { method: C::SIGNATURE, error })?;
+    C::abi_decode_returns(&output, true)
+        .map_err(|_| SystemConfigProviderError::Decode(C::SIGNATURE))
+}
+
+/// A [SystemConfigProvider] backed by an alloy [Provider].
+///
+/// [Provider]: alloy_provider::Provider
+#[derive(Debug, Clone)]
+pub struct AlloySystemConfigProvider<P> {
+    /// The inner L1 provider.
+    provider: P,
+}
+
+impl<P> AlloySystemConfigProvider<P> {
+    /// Creates a new [AlloySystemConfigProvider] with the given L1 provider.
+    pub const fn new(provider: P) -> Self {
+        Self { provider }
+    }
+}
+
+#[async_trait]
+impl<P: alloy_provider::Provider + Send + Sync> SystemConfigProvider
+    for AlloySystemConfigProvider<P>
+{
+    type Error = alloy_transport::TransportError;
+
+    async fn call(
+        &self,
+        to: Address,
+        input: Bytes,
+        block_number: u64,
+    ) -> Result<Bytes, Self::Error> {
+        let tx = alloy_rpc_types_eth::TransactionRequest::default().to(to).input(input.into());
+        self.provider.call(&tx).block(block_number.into()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::{String, ToString};
+    use alloy_primitives::{address, map::HashMap, U256};
+    use alloy_sol_types::SolValue;
+
+    const SYSTEM_CONFIG: Address = address!("229047fed2591dbec1eF1118d64F7aF3dB9EB290");
+
+    /// A mock provider answering calls to the `SystemConfig` contract by selector.
+    #[derive(Debug, Default)]
+    struct MockProvider {
+        responses: HashMap<[u8; 4], Bytes>,
+    }
+
+    impl MockProvider {
+        fn with<C: SolCall>(mut self, value: impl SolValue) -> Self {
+            self.responses.insert(C::SELECTOR, value.abi_encode().into());
+            self
+        }
+    }
+
+    #[async_trait]
+    impl SystemConfigProvider for MockProvider {
+        type Error = String;
+
+        async fn call(
+            &self,
+            to: Address,
+            input: Bytes,
+            block_number: u64,
+        ) -> Result<Bytes, Self::Error> {
+            assert_eq!(to, SYSTEM_CONFIG);
+            assert_eq!(block_number, 100);
+            let selector: [u8; 4] = input[..4].try_into().unwrap();
+            self.responses.get(&selector).cloned().ok_or_else(|| "execution reverted".to_string())
+        }
+    }
+
+    fn mock_provider() -> MockProvider {
+        let batcher = address!("6887246668a3b87F54DeB3b94Ba47a6f63F32985");
+        MockProvider::default()
+            .with::<ISystemConfig::batcherHashCall>(batcher.into_word())
+            .with::<ISystemConfig::overheadCall>(U256::from(188))
+            .with::<ISystemConfig::scalarCall>(U256::from(684_000))
+            .with::<ISystemConfig::gasLimitCall>(30_000_000u64)
+            .with::<ISystemConfig::basefeeScalarCall>(1_368u32)
+            .with::<ISystemConfig::blobbasefeeScalarCall>(810_949u32)
+            .with::<ISystemConfig::eip1559DenominatorCall>(250u32)
+            .with::<ISystemConfig::eip1559ElasticityCall>(6u32)
+    }
+
+    #[tokio::test]
+    async fn test_system_config_bedrock() {
+        let config =
+            mock_provider().system_config(SYSTEM_CONFIG, 100, OpHardfork::Regolith).await.unwrap();
+        assert_eq!(
+            config,
+            SystemConfig {
+                batcher_address: address!("6887246668a3b87F54DeB3b94Ba47a6f63F32985"),
+                overhead: U256::from(188),
+                scalar: U256::from(684_000),
+                gas_limit: 30_000_000,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_system_config_holocene() {
+        let config =
+            mock_provider().system_config(SYSTEM_CONFIG, 100, OpHardfork::Holocene).await.unwrap();
+        assert_eq!(config.base_fee_scalar, Some(1_368));
+        assert_eq!(config.blob_base_fee_scalar, Some(810_949));
+        assert_eq!(config.eip1559_denominator, Some(250));
+        assert_eq!(config.eip1559_elasticity, Some(6));
+        assert_eq!(config.operator_fee_scalar, None);
+        assert_eq!(config.operator_fee_constant, None);
+    }
+
+    #[tokio::test]
+    async fn test_system_config_missing_getter() {
+        let err = mock_provider()
+            .system_config(SYSTEM_CONFIG, 100, OpHardfork::Isthmus)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err,
+            SystemConfigProviderError::Call {
+                method: "operatorFeeScalar()",
+                error: "execution reverted".to_string(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_system_config_invalid_return_data() {
+        let mut provider = mock_provider();
+        provider.responses.insert(ISystemConfig::gasLimitCall::SELECTOR, Bytes::new());
+        let err =
+            provider.system_config(SYSTEM_CONFIG, 100, OpHardfork::Bedrock).await.unwrap_err();
+        assert_eq!(err, SystemConfigProviderError::Decode("gasLimit()"));
+    }
+}
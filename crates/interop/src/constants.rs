@@ -11,3 +11,6 @@ pub const MESSAGE_EXPIRY_WINDOW: u64 = 180 * 24 * 60 * 60;
 
 /// The current version of the super root encoding format.
 pub const SUPER_ROOT_VERSION: u8 = 1;
+
+/// The version byte of the encoded transition state, distinguishing it from a super root.
+pub const TRANSITION_STATE_VERSION: u8 = 255;
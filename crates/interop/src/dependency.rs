@@ -0,0 +1,308 @@
+//! Contains the [DependencySet] of an interop cluster, and the checks of the message graph it
+//! induces.
+
+use crate::{DependencyError, ExecutingMessage, MESSAGE_EXPIRY_WINDOW};
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+use alloy_primitives::{map::HashMap, U256};
+
+/// The dependency of the interop cluster on a chain.
+///
+/// <https://github.com/ethereum-optimism/optimism/blob/develop/op-supervisor/supervisor/backend/depset/depset.go>
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct ChainDependency {
+    /// The index of the chain in the dependency set.
+    pub chain_index: u32,
+    /// The timestamp from which the chain can execute messages.
+    pub activation_time: u64,
+    /// The timestamp from which messages initiated on the chain can be executed.
+    pub history_min_time: u64,
+}
+
+/// The set of chains that can exchange messages with each other, as loaded by `op-supervisor`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct DependencySet {
+    /// The dependencies of the set, by chain ID.
+    pub dependencies: HashMap<u64, ChainDependency>,
+    /// Overrides the protocol [MESSAGE_EXPIRY_WINDOW], in seconds.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub override_message_expiry_window: Option<u64>,
+}
+
+impl DependencySet {
+    /// Returns the [ChainDependency] of the given chain, if it is part of the set.
+    pub fn dependency(&self, chain_id: u64) -> Option<&ChainDependency> {
+        self.dependencies.get(&chain_id)
+    }
+
+    /// Returns the message expiry window of the set, in seconds.
+    pub fn message_expiry_window(&self) -> u64 {
+        self.override_message_expiry_window.unwrap_or(MESSAGE_EXPIRY_WINDOW)
+    }
+
+    /// Returns true if the given chain can execute messages at the given timestamp.
+    pub fn can_execute_at(&self, chain_id: u64, timestamp: u64) -> bool {
+        self.dependency(chain_id).is_some_and(|dep| timestamp >= dep.activation_time)
+    }
+
+    /// Returns true if messages initiated on the given chain at the given timestamp can be
+    /// executed.
+    pub fn can_initiate_at(&self, chain_id: u64, timestamp: u64) -> bool {
+        self.dependency(chain_id).is_some_and(|dep| timestamp >= dep.history_min_time)
+    }
+
+    /// Checks that the [ExecutingMessage] executed on the given chain at the given timestamp
+    /// references an initiating chain of the set.
+    pub fn check_message(
+        &self,
+        executing_chain_id: u64,
+        executing_timestamp: u64,
+        message: &ExecutingMessage,
+    ) -> Result<(), DependencyError> {
+        if !self.can_execute_at(executing_chain_id, executing_timestamp) {
+            return Err(DependencyError::CannotExecute {
+                chain_id: executing_chain_id,
+                timestamp: executing_timestamp,
+            });
+        }
+
+        let id = &message.id;
+        let initiating = u64::try_from(id.chainId)
+            .ok()
+            .zip(u64::try_from(id.timestamp).ok())
+            .filter(|(chain_id, timestamp)| self.can_initiate_at(*chain_id, *timestamp));
+        if initiating.is_none() {
+            return Err(DependencyError::CannotInitiate {
+                chain_id: id.chainId,
+                timestamp: id.timestamp,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// The executing messages of a block, with the index of the log executing each of them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BlockMessages {
+    /// The chain ID of the block.
+    pub chain_id: u64,
+    /// The timestamp of the block.
+    pub timestamp: u64,
+    /// The executing messages of the block, by log index.
+    pub messages: Vec<(u64, ExecutingMessage)>,
+}
+
+/// Checks that the executing messages of a batch of blocks do not depend on each other cyclically.
+///
+/// Messages referencing a log with the same timestamp form a graph across chains, in which every
+/// log also depends on the logs preceding it in its block. A cycle in that graph means no block
+/// can be considered cross-unsafe before the others, making all of them invalid.
+pub fn check_cyclic_dependencies(blocks: &[BlockMessages]) -> Result<(), DependencyError> {
+    // Nodes are logs, keyed by chain ID, timestamp and log index. Edges point from a log to the
+    // logs it depends on.
+    let mut nodes = BTreeMap::<(u64, u64, u64), Vec<(u64, u64, u64)>>::new();
+    for block in blocks {
+        for (log_index, message) in &block.messages {
+            let id = &message.id;
+            let executing = (block.chain_id, block.timestamp, *log_index);
+            nodes.entry(executing).or_default();
+            if id.timestamp != U256::from(block.timestamp) {
+                continue;
+            }
+            let (Ok(chain_id), Ok(initiating_index)) =
+                (u64::try_from(id.chainId), u64::try_from(id.logIndex))
+            else {
+                continue;
+            };
+            let initiating = (chain_id, block.timestamp, initiating_index);
+            nodes.entry(initiating).or_default();
+            nodes.entry(executing).or_default().push(initiating);
+        }
+    }
+
+    // Logs of the same block depend on their predecessor, which the ordered keys make adjacent.
+    let keys = nodes.keys().copied().collect::<Vec<_>>();
+    for pair in keys.windows(2) {
+        let (prev, next) = (pair[0], pair[1]);
+        if prev.0 == next.0 && prev.1 == next.1 {
+            nodes.entry(next).or_default().push(prev);
+        }
+    }
+
+    // Kahn's algorithm: the graph is acyclic iff all nodes can be removed in dependency order.
+    let index = keys.iter().enumerate().map(|(i, key)| (*key, i)).collect::<BTreeMap<_, _>>();
+    let mut dependents = vec![Vec::new(); keys.len()];
+    let mut pending = vec![0usize; keys.len()];
+    for (key, deps) in &nodes {
+        let node = index[key];
+        for dep in deps {
+            dependents[index[dep]].push(node);
+            pending[node] += 1;
+        }
+    }
+    let mut ready = (0..keys.len()).filter(|node| pending[*node] == 0).collect::<Vec<_>>();
+    let mut resolved = 0;
+    while let Some(node) = ready.pop() {
+        resolved += 1;
+        for &dependent in &dependents[node] {
+            pending[dependent] -= 1;
+            if pending[dependent] == 0 {
+                ready.push(dependent);
+            }
+        }
+    }
+
+    if resolved == keys.len() {
+        Ok(())
+    } else {
+        let (chain_id, timestamp, log_index) = keys[pending.iter().position(|p| *p > 0).unwrap()];
+        Err(DependencyError::CyclicDependency { chain_id, timestamp, log_index })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MessageIdentifier;
+    use alloy_primitives::Address;
+
+    fn dependency_set() -> DependencySet {
+        DependencySet {
+            dependencies: HashMap::from_iter([
+                (
+                    900,
+                    ChainDependency { chain_index: 900, activation_time: 10, history_min_time: 5 },
+                ),
+                (
+                    901,
+                    ChainDependency { chain_index: 901, activation_time: 0, history_min_time: 0 },
+                ),
+            ]),
+            override_message_expiry_window: None,
+        }
+    }
+
+    fn message(chain_id: u64, timestamp: u64, log_index: u64) -> ExecutingMessage {
+        ExecutingMessage {
+            id: MessageIdentifier {
+                origin: Address::ZERO,
+                blockNumber: U256::from(1),
+                logIndex: U256::from(log_index),
+                timestamp: U256::from(timestamp),
+                chainId: U256::from(chain_id),
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_check_message() {
+        let set = dependency_set();
+        assert_eq!(set.check_message(901, 20, &message(900, 5, 0)), Ok(()));
+        assert_eq!(
+            set.check_message(900, 9, &message(901, 5, 0)),
+            Err(DependencyError::CannotExecute { chain_id: 900, timestamp: 9 })
+        );
+        assert_eq!(
+            set.check_message(901, 20, &message(900, 4, 0)),
+            Err(DependencyError::CannotInitiate {
+                chain_id: U256::from(900),
+                timestamp: U256::from(4)
+            })
+        );
+        assert_eq!(
+            set.check_message(901, 20, &message(902, 5, 0)),
+            Err(DependencyError::CannotInitiate {
+                chain_id: U256::from(902),
+                timestamp: U256::from(5)
+            })
+        );
+    }
+
+    #[test]
+    fn test_message_expiry_window() {
+        let mut set = dependency_set();
+        assert_eq!(set.message_expiry_window(), MESSAGE_EXPIRY_WINDOW);
+        set.override_message_expiry_window = Some(60);
+        assert_eq!(set.message_expiry_window(), 60);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_dependency_set_serde() {
+        let raw = r#"{
+            "dependencies": {
+                "900": { "chainIndex": 900, "activationTime": 10, "historyMinTime": 5 },
+                "901": { "chainIndex": 901, "activationTime": 0, "historyMinTime": 0 }
+            }
+        }"#;
+        let set: DependencySet = serde_json::from_str(raw).unwrap();
+        assert_eq!(set, dependency_set());
+        let json = serde_json::to_value(&set).unwrap();
+        assert_eq!(serde_json::from_value::<DependencySet>(json).unwrap(), set);
+    }
+
+    #[test]
+    fn test_check_cyclic_dependencies_acyclic() {
+        let blocks = [
+            BlockMessages {
+                chain_id: 900,
+                timestamp: 10,
+                messages: vec![(1, message(901, 10, 0)), (2, message(900, 8, 5))],
+            },
+            BlockMessages {
+                chain_id: 901,
+                timestamp: 10,
+                messages: vec![(3, message(900, 10, 0))],
+            },
+        ];
+        assert_eq!(check_cyclic_dependencies(&blocks), Ok(()));
+    }
+
+    #[test]
+    fn test_check_cyclic_dependencies_cross_chain_cycle() {
+        // 900:1 executes 901:3, which executes 900:2, which follows 900:1.
+        let blocks = [
+            BlockMessages {
+                chain_id: 900,
+                timestamp: 10,
+                messages: vec![(1, message(901, 10, 3))],
+            },
+            BlockMessages {
+                chain_id: 901,
+                timestamp: 10,
+                messages: vec![(3, message(900, 10, 2))],
+            },
+        ];
+        assert!(matches!(
+            check_cyclic_dependencies(&blocks),
+            Err(DependencyError::CyclicDependency { timestamp: 10, .. })
+        ));
+    }
+
+    #[test]
+    fn test_check_cyclic_dependencies_same_block() {
+        // A log cannot execute itself, nor a later log of its own block.
+        let blocks = [BlockMessages {
+            chain_id: 900,
+            timestamp: 10,
+            messages: vec![(1, message(900, 10, 1))],
+        }];
+        assert!(check_cyclic_dependencies(&blocks).is_err());
+        let blocks = [BlockMessages {
+            chain_id: 900,
+            timestamp: 10,
+            messages: vec![(1, message(900, 10, 2))],
+        }];
+        assert!(check_cyclic_dependencies(&blocks).is_err());
+        let blocks = [BlockMessages {
+            chain_id: 900,
+            timestamp: 10,
+            messages: vec![(1, message(900, 10, 0))],
+        }];
+        assert_eq!(check_cyclic_dependencies(&blocks), Ok(()));
+    }
+}
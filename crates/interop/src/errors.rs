@@ -1,5 +1,8 @@
 //! Error types for interop.
 
+use crate::MessageIdentifier;
+use alloc::boxed::Box;
+use alloy_primitives::{Address, B256, U256};
 use thiserror::Error;
 
 /// An error type for the [SuperRoot] struct's serialization and deserialization.
@@ -17,3 +20,111 @@ pub enum SuperRootError {
 
 /// A [Result] alias for the [SuperRootError] type.
 pub type SuperRootResult<T> = core::result::Result<T, SuperRootError>;
+
+/// An error validating an [ExecutingMessage] against its initiating log.
+///
+/// [ExecutingMessage]: crate::ExecutingMessage
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum MessageValidationError {
+    /// No initiating log is known for the message identifier.
+    #[error("Unknown initiating message: {0:?}")]
+    UnknownMessage(Box<MessageIdentifier>),
+    /// The origin of the identifier does not match the emitter of the initiating log.
+    #[error("Origin mismatch: expected {expected}, got {got}")]
+    OriginMismatch {
+        /// The emitter of the initiating log.
+        expected: Address,
+        /// The origin of the identifier.
+        got: Address,
+    },
+    /// The timestamp of the identifier does not match the block of the initiating log.
+    #[error("Timestamp mismatch: expected {expected}, got {got}")]
+    TimestampMismatch {
+        /// The timestamp of the initiating block.
+        expected: u64,
+        /// The timestamp of the identifier.
+        got: u64,
+    },
+    /// The message hash does not match the payload hash of the initiating log.
+    #[error("Payload hash mismatch: expected {expected}, got {got}")]
+    PayloadHashMismatch {
+        /// The payload hash of the initiating log.
+        expected: B256,
+        /// The message hash of the executing message.
+        got: B256,
+    },
+    /// The initiating block is after the executing block.
+    #[error("Message initiated at {initiated_at}, after its execution at {executed_at}")]
+    InitiatedAfterExecution {
+        /// The timestamp of the initiating block.
+        initiated_at: u64,
+        /// The timestamp of the executing block.
+        executed_at: u64,
+    },
+    /// The initiating log is in the executing block, at or after the executing log.
+    #[error("Message initiated at log {initiating_log_index}, not before its execution at log {executing_log_index}")]
+    InitiatedAfterExecutingLog {
+        /// The log index of the initiating log.
+        initiating_log_index: u64,
+        /// The log index of the executing log.
+        executing_log_index: u64,
+    },
+    /// The message is executed after the expiry window of the initiating message.
+    #[error("Message initiated at {initiated_at} expired at {executed_at}")]
+    Expired {
+        /// The timestamp of the initiating block.
+        initiated_at: u64,
+        /// The timestamp of the executing block.
+        executed_at: u64,
+    },
+}
+
+/// An error checking messages against the [DependencySet].
+///
+/// [DependencySet]: crate::DependencySet
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum DependencyError {
+    /// The executing chain cannot execute messages at the given timestamp.
+    #[error("Chain {chain_id} cannot execute messages at {timestamp}")]
+    CannotExecute {
+        /// The executing chain ID.
+        chain_id: u64,
+        /// The executing timestamp.
+        timestamp: u64,
+    },
+    /// The initiating chain of the message is not in the set, or its message predates the
+    /// history of the chain.
+    #[error("Chain {chain_id} cannot initiate messages at {timestamp}")]
+    CannotInitiate {
+        /// The initiating chain ID.
+        chain_id: U256,
+        /// The initiating timestamp.
+        timestamp: U256,
+    },
+    /// Messages with the same timestamp depend on each other cyclically.
+    #[error("Cyclic message dependency at log {log_index} of chain {chain_id} at {timestamp}")]
+    CyclicDependency {
+        /// The chain ID of a log in, or depending on, the cycle.
+        chain_id: u64,
+        /// The timestamp of the cycle.
+        timestamp: u64,
+        /// The index of a log in, or depending on, the cycle.
+        log_index: u64,
+    },
+}
+
+/// An error decoding a [TransitionState].
+///
+/// [TransitionState]: crate::TransitionState
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TransitionStateError {
+    /// Invalid transition state version byte
+    #[error("Invalid transition state version byte")]
+    InvalidVersionByte,
+    /// Unexpected encoded transition state length
+    #[error("Unexpected encoded transition state length")]
+    UnexpectedLength,
+    /// Invalid RLP encoding of the transition state
+    #[error("Invalid transition state RLP: {0}")]
+    Rlp(alloy_rlp::Error),
+}
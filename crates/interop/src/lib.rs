@@ -10,15 +10,22 @@
 extern crate alloc;
 
 #[cfg(feature = "interop")]
+#[allow(deprecated)]
 mod supervisor;
 #[cfg(feature = "interop")]
+#[allow(deprecated)]
 pub use supervisor::{Supervisor, SupervisorClient, SupervisorError};
 
 mod root;
 pub use root::{ChainRootInfo, OutputRootWithChain, SuperRoot, SuperRootResponse};
 
+mod transition;
+pub use transition::{OptimisticBlock, TransitionState};
+
 mod errors;
-pub use errors::{SuperRootError, SuperRootResult};
+pub use errors::{
+    DependencyError, MessageValidationError, SuperRootError, SuperRootResult, TransitionStateError,
+};
 
 mod safety;
 pub use safety::SafetyLevel;
@@ -29,8 +36,16 @@ pub use message::{
     MessagePayload,
 };
 
+mod dependency;
+pub use dependency::{check_cyclic_dependencies, BlockMessages, ChainDependency, DependencySet};
+
+mod validator;
+pub use validator::{InitiatingLog, LocalMessageValidator};
+
 mod derived;
 pub use derived::DerivedIdPair;
 
 mod constants;
-pub use constants::{CROSS_L2_INBOX_ADDRESS, MESSAGE_EXPIRY_WINDOW, SUPER_ROOT_VERSION};
+pub use constants::{
+    CROSS_L2_INBOX_ADDRESS, MESSAGE_EXPIRY_WINDOW, SUPER_ROOT_VERSION, TRANSITION_STATE_VERSION,
+};
@@ -1,7 +1,12 @@
 //! Message safety level for interoperability.
 
+use core::cmp::Ordering;
+
 /// The safety level of a message.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Valid levels are totally ordered, from [SafetyLevel::Unsafe] up to [SafetyLevel::Finalized].
+/// [SafetyLevel::Invalid] is only equal to itself, and is not comparable to any valid level.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum SafetyLevel {
@@ -19,12 +24,120 @@ pub enum SafetyLevel {
     Invalid,
 }
 
+impl SafetyLevel {
+    /// Returns the rank of the level in the safety order, or [None] for [SafetyLevel::Invalid].
+    const fn rank(&self) -> Option<u8> {
+        match self {
+            Self::Unsafe => Some(0),
+            Self::CrossUnsafe => Some(1),
+            Self::LocalSafe => Some(2),
+            Self::Safe => Some(3),
+            Self::Finalized => Some(4),
+            Self::Invalid => None,
+        }
+    }
+
+    /// Returns true if the level is at least as safe as the given minimum level, i.e.
+    /// `self >= min`.
+    ///
+    /// [SafetyLevel::Invalid] neither meets, nor is met by, any valid level.
+    pub fn meets(&self, min: Self) -> bool {
+        *self >= min
+    }
+
+    /// Returns the next safer level, or [None] for [SafetyLevel::Finalized] and
+    /// [SafetyLevel::Invalid].
+    pub const fn promote(&self) -> Option<Self> {
+        match self {
+            Self::Unsafe => Some(Self::CrossUnsafe),
+            Self::CrossUnsafe => Some(Self::LocalSafe),
+            Self::LocalSafe => Some(Self::Safe),
+            Self::Safe => Some(Self::Finalized),
+            Self::Finalized | Self::Invalid => None,
+        }
+    }
+
+    /// Returns the next less safe level, or [None] for [SafetyLevel::Unsafe] and
+    /// [SafetyLevel::Invalid].
+    pub const fn demote(&self) -> Option<Self> {
+        match self {
+            Self::Finalized => Some(Self::Safe),
+            Self::Safe => Some(Self::LocalSafe),
+            Self::LocalSafe => Some(Self::CrossUnsafe),
+            Self::CrossUnsafe => Some(Self::Unsafe),
+            Self::Unsafe | Self::Invalid => None,
+        }
+    }
+}
+
+impl PartialOrd for SafetyLevel {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self.rank(), other.rank()) {
+            (Some(a), Some(b)) => Some(a.cmp(&b)),
+            (None, None) => Some(Ordering::Equal),
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
-#[cfg(feature = "serde")]
 mod tests {
     use super::*;
 
     #[test]
+    fn test_safety_level_order() {
+        assert!(SafetyLevel::Finalized > SafetyLevel::Safe);
+        assert!(SafetyLevel::Safe > SafetyLevel::LocalSafe);
+        assert!(SafetyLevel::LocalSafe > SafetyLevel::CrossUnsafe);
+        assert!(SafetyLevel::CrossUnsafe > SafetyLevel::Unsafe);
+        assert_eq!(SafetyLevel::Invalid.partial_cmp(&SafetyLevel::Unsafe), None);
+        assert_eq!(SafetyLevel::Finalized.partial_cmp(&SafetyLevel::Invalid), None);
+    }
+
+    #[test]
+    fn test_safety_level_meets() {
+        assert!(SafetyLevel::Safe.meets(SafetyLevel::Safe));
+        assert!(SafetyLevel::Finalized.meets(SafetyLevel::CrossUnsafe));
+        assert!(!SafetyLevel::LocalSafe.meets(SafetyLevel::Safe));
+        assert!(!SafetyLevel::Invalid.meets(SafetyLevel::Unsafe));
+        assert!(!SafetyLevel::Finalized.meets(SafetyLevel::Invalid));
+    }
+
+    #[test]
+    fn test_safety_level_meets_matches_order() {
+        let levels = [
+            SafetyLevel::Finalized,
+            SafetyLevel::Safe,
+            SafetyLevel::LocalSafe,
+            SafetyLevel::CrossUnsafe,
+            SafetyLevel::Unsafe,
+            SafetyLevel::Invalid,
+        ];
+        for a in levels {
+            for b in levels {
+                assert_eq!(a >= b, a.meets(b), "{a:?} >= {b:?}");
+                // Equality is consistent with the order.
+                assert_eq!(a == b, a.partial_cmp(&b) == Some(Ordering::Equal), "{a:?} == {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_safety_level_promote_demote() {
+        let mut level = SafetyLevel::Unsafe;
+        while let Some(next) = level.promote() {
+            assert!(next > level);
+            assert_eq!(next.demote(), Some(level));
+            level = next;
+        }
+        assert_eq!(level, SafetyLevel::Finalized);
+        assert_eq!(SafetyLevel::Unsafe.demote(), None);
+        assert_eq!(SafetyLevel::Invalid.promote(), None);
+        assert_eq!(SafetyLevel::Invalid.demote(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
     fn test_safety_level_serde() {
         let level = SafetyLevel::Finalized;
         let json = serde_json::to_string(&level).unwrap();
@@ -35,6 +148,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "serde")]
     fn test_serde_safety_level_fails() {
         let json = r#""failed""#;
         let level: Result<SafetyLevel, _> = serde_json::from_str(json);
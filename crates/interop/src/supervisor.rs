@@ -1,18 +1,22 @@
 //! Defines the supervisor API and Client.
+//!
+//! Deprecated in favor of the `SupervisorApiClient` of `maili-rpc`, which covers the full
+//! `op-supervisor` RPC surface.
 
 use crate::{DerivedIdPair, ExecutingMessage, MessageIdentifier, SafetyLevel, SuperRootResponse};
-use alloc::{boxed::Box, vec::Vec};
+use alloc::boxed::Box;
 use alloy_eips::eip1898::BlockNumHash;
-use alloy_primitives::B256;
+use alloy_primitives::{map::HashMap, B256, U64};
 use alloy_rpc_client::ReqwestClient;
+use alloy_transport::TransportError;
 use async_trait::async_trait;
-use derive_more::{Display, From};
 use maili_protocol::BlockInfo;
 
 /// An interface for the `op-supervisor` component of the OP Stack.
 ///
 /// <https://github.com/ethereum-optimism/optimism/blob/develop/op-supervisor/supervisor/frontend/frontend.go#L18-L28>
 #[async_trait]
+#[deprecated(note = "use `maili_rpc::SupervisorApiClient` instead")]
 pub trait Supervisor {
     /// The error returned by supervisor methods.
     type Error: Send + Sync;
@@ -56,21 +60,25 @@ pub trait Supervisor {
         timestamp: u64,
     ) -> Result<SuperRootResponse, Self::Error>;
 
-    /// Returns all safe derived blocks at the given block.
+    /// Returns the latest safe L2 block of every chain, keyed by chain ID, derived from the given
+    /// L1 block.
     async fn all_safe_derived_at(
         &self,
         derived_from: BlockNumHash,
-    ) -> Result<Vec<(u32, BlockNumHash)>, Self::Error>;
+    ) -> Result<HashMap<u64, BlockNumHash>, Self::Error>;
 }
 
 /// An error from the `op-supervisor`.
-#[derive(Copy, Clone, Debug, Display, From, thiserror::Error)]
+#[deprecated(note = "use `maili_rpc::SupervisorApiClient` instead")]
+#[derive(Debug, thiserror::Error)]
 pub enum SupervisorError {
-    /// A failed request.
-    RequestFailed,
+    /// The RPC request to the supervisor failed.
+    #[error("Supervisor RPC request failed: {0}")]
+    Rpc(#[from] TransportError),
 }
 
 /// A supervisor client.
+#[deprecated(note = "use `maili_rpc::SupervisorApiClient` instead")]
 #[derive(Debug, Clone)]
 pub struct SupervisorClient {
     /// The inner RPC client.
@@ -93,10 +101,7 @@ impl Supervisor for SupervisorClient {
         identifier: MessageIdentifier,
         payload_hash: B256,
     ) -> Result<SafetyLevel, Self::Error> {
-        self.client
-            .request("supervisor_checkMessage", (identifier, payload_hash))
-            .await
-            .map_err(|_| SupervisorError::RequestFailed)
+        Ok(self.client.request("supervisor_checkMessage", (identifier, payload_hash)).await?)
     }
 
     async fn check_messages(
@@ -104,40 +109,227 @@ impl Supervisor for SupervisorClient {
         messages: &[ExecutingMessage],
         min_safety: SafetyLevel,
     ) -> Result<(), Self::Error> {
-        self.client
-            .request("supervisor_checkMessages", (messages, min_safety))
-            .await
-            .map_err(|_| SupervisorError::RequestFailed)
+        Ok(self.client.request("supervisor_checkMessages", (messages, min_safety)).await?)
     }
 
-    async fn cross_derived_from(&self, _: u32, _: BlockNumHash) -> Result<BlockInfo, Self::Error> {
-        unimplemented!()
+    async fn cross_derived_from(
+        &self,
+        chain_id: u32,
+        derived: BlockNumHash,
+    ) -> Result<BlockInfo, Self::Error> {
+        Ok(self
+            .client
+            .request("supervisor_crossDerivedFrom", (U64::from(chain_id), derived))
+            .await?)
     }
 
-    async fn local_unsafe(&self, _: u32) -> Result<BlockNumHash, Self::Error> {
-        unimplemented!()
+    async fn local_unsafe(&self, chain_id: u32) -> Result<BlockNumHash, Self::Error> {
+        Ok(self.client.request("supervisor_localUnsafe", (U64::from(chain_id),)).await?)
     }
 
-    async fn cross_safe(&self, _: u32) -> Result<DerivedIdPair, Self::Error> {
-        unimplemented!()
+    async fn cross_safe(&self, chain_id: u32) -> Result<DerivedIdPair, Self::Error> {
+        Ok(self.client.request("supervisor_crossSafe", (U64::from(chain_id),)).await?)
     }
 
-    async fn finalized(&self, _: u32) -> Result<BlockNumHash, Self::Error> {
-        unimplemented!()
+    async fn finalized(&self, chain_id: u32) -> Result<BlockNumHash, Self::Error> {
+        Ok(self.client.request("supervisor_finalized", (U64::from(chain_id),)).await?)
     }
 
     async fn finalized_l1(&self) -> Result<BlockInfo, Self::Error> {
-        unimplemented!()
+        Ok(self.client.request_noparams("supervisor_finalizedL1").await?)
     }
 
-    async fn super_root_at_timestamp(&self, _: u64) -> Result<SuperRootResponse, Self::Error> {
-        unimplemented!()
+    async fn super_root_at_timestamp(
+        &self,
+        timestamp: u64,
+    ) -> Result<SuperRootResponse, Self::Error> {
+        Ok(self.client.request("supervisor_superRootAtTimestamp", (U64::from(timestamp),)).await?)
     }
 
     async fn all_safe_derived_at(
         &self,
-        _: BlockNumHash,
-    ) -> Result<Vec<(u32, BlockNumHash)>, Self::Error> {
-        unimplemented!()
+        derived_from: BlockNumHash,
+    ) -> Result<HashMap<u64, BlockNumHash>, Self::Error> {
+        Ok(self.client.request("supervisor_allSafeDerivedAt", (derived_from,)).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ChainRootInfo;
+    use alloc::{format, vec};
+    use alloy_primitives::{b256, Bytes};
+    use alloy_rpc_client::ClientBuilder;
+    use alloy_transport::RpcError;
+    use jsonrpsee::{
+        server::{RpcModule, Server, ServerHandle},
+        types::ErrorObjectOwned,
+    };
+
+    const HASH: B256 = b256!("0000000000000000000000000000000000000000000000000000000000000001");
+
+    /// Starts a mock supervisor on a random local port and returns a client connected to it.
+    async fn mock_supervisor() -> (SupervisorClient, ServerHandle) {
+        let mut module = RpcModule::new(());
+        module
+            .register_method("supervisor_checkMessage", |_, _, _| {
+                Ok::<_, ErrorObjectOwned>(SafetyLevel::CrossUnsafe)
+            })
+            .unwrap();
+        module
+            .register_method("supervisor_checkMessages", |params, _, _| {
+                let (_, min_safety): (Vec<ExecutingMessage>, SafetyLevel) = params.parse()?;
+                if min_safety == SafetyLevel::Finalized {
+                    return Err(ErrorObjectOwned::owned(-320600, "conflicting data", None::<()>));
+                }
+                Ok(())
+            })
+            .unwrap();
+        module
+            .register_method("supervisor_crossDerivedFrom", |params, _, _| {
+                let (chain_id, derived): (U64, BlockNumHash) = params.parse()?;
+                Ok::<_, ErrorObjectOwned>(BlockInfo {
+                    hash: derived.hash,
+                    number: derived.number + chain_id.to::<u64>(),
+                    parent_hash: B256::ZERO,
+                    timestamp: 100,
+                })
+            })
+            .unwrap();
+        for method in ["supervisor_localUnsafe", "supervisor_finalized"] {
+            module
+                .register_method(method, |params, _, _| {
+                    let (chain_id,): (U64,) = params.parse()?;
+                    Ok::<_, ErrorObjectOwned>(BlockNumHash::new(chain_id.to(), HASH))
+                })
+                .unwrap();
+        }
+        module
+            .register_method("supervisor_crossSafe", |params, _, _| {
+                let (chain_id,): (U64,) = params.parse()?;
+                Ok::<_, ErrorObjectOwned>(DerivedIdPair {
+                    derived_from: BlockNumHash::new(1, HASH),
+                    derived: BlockNumHash::new(chain_id.to(), HASH),
+                })
+            })
+            .unwrap();
+        module
+            .register_method("supervisor_finalizedL1", |_, _, _| {
+                Ok::<_, ErrorObjectOwned>(BlockInfo { hash: HASH, number: 1, ..Default::default() })
+            })
+            .unwrap();
+        module
+            .register_method("supervisor_superRootAtTimestamp", |params, _, _| {
+                let (timestamp,): (U64,) = params.parse()?;
+                Ok::<_, ErrorObjectOwned>(SuperRootResponse {
+                    timestamp: timestamp.to(),
+                    super_root: HASH,
+                    chains: vec![ChainRootInfo {
+                        chain_id: 10,
+                        canonical: HASH,
+                        pending: Bytes::from_static(&[0xFF]),
+                    }],
+                })
+            })
+            .unwrap();
+        module
+            .register_method("supervisor_allSafeDerivedAt", |params, _, _| {
+                let (derived_from,): (BlockNumHash,) = params.parse()?;
+                Ok::<_, ErrorObjectOwned>(HashMap::<u64, BlockNumHash>::from_iter([
+                    (10, BlockNumHash::new(derived_from.number, HASH)),
+                    (11, BlockNumHash::new(derived_from.number + 1, HASH)),
+                ]))
+            })
+            .unwrap();
+
+        let server = Server::builder().build("127.0.0.1:0").await.unwrap();
+        let addr = server.local_addr().unwrap();
+        let handle = server.start(module);
+        let client = ClientBuilder::default().http(format!("http://{addr}").parse().unwrap());
+        (SupervisorClient::new(client), handle)
+    }
+
+    #[tokio::test]
+    async fn test_check_message() {
+        let (client, _handle) = mock_supervisor().await;
+        let level = client.check_message(MessageIdentifier::default(), HASH).await.unwrap();
+        assert_eq!(level, SafetyLevel::CrossUnsafe);
+    }
+
+    #[tokio::test]
+    async fn test_check_messages() {
+        let (client, _handle) = mock_supervisor().await;
+        client.check_messages(&[ExecutingMessage::default()], SafetyLevel::Safe).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_check_messages_rpc_error() {
+        let (client, _handle) = mock_supervisor().await;
+        let err = client
+            .check_messages(&[ExecutingMessage::default()], SafetyLevel::Finalized)
+            .await
+            .unwrap_err();
+        let SupervisorError::Rpc(RpcError::ErrorResp(payload)) = err else {
+            panic!("expected an error response, got {err:?}");
+        };
+        assert_eq!(payload.code, -320600);
+        assert_eq!(payload.message, "conflicting data");
+    }
+
+    #[tokio::test]
+    async fn test_cross_derived_from() {
+        let (client, _handle) = mock_supervisor().await;
+        let block = client.cross_derived_from(10, BlockNumHash::new(5, HASH)).await.unwrap();
+        assert_eq!(
+            block,
+            BlockInfo { hash: HASH, number: 15, parent_hash: B256::ZERO, timestamp: 100 }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_local_unsafe() {
+        let (client, _handle) = mock_supervisor().await;
+        assert_eq!(client.local_unsafe(10).await.unwrap(), BlockNumHash::new(10, HASH));
+    }
+
+    #[tokio::test]
+    async fn test_cross_safe() {
+        let (client, _handle) = mock_supervisor().await;
+        let pair = client.cross_safe(10).await.unwrap();
+        assert_eq!(pair.derived_from, BlockNumHash::new(1, HASH));
+        assert_eq!(pair.derived, BlockNumHash::new(10, HASH));
+    }
+
+    #[tokio::test]
+    async fn test_finalized() {
+        let (client, _handle) = mock_supervisor().await;
+        assert_eq!(client.finalized(10).await.unwrap(), BlockNumHash::new(10, HASH));
+    }
+
+    #[tokio::test]
+    async fn test_finalized_l1() {
+        let (client, _handle) = mock_supervisor().await;
+        let block = client.finalized_l1().await.unwrap();
+        assert_eq!(block, BlockInfo { hash: HASH, number: 1, ..Default::default() });
+    }
+
+    #[tokio::test]
+    async fn test_super_root_at_timestamp() {
+        let (client, _handle) = mock_supervisor().await;
+        let response = client.super_root_at_timestamp(42).await.unwrap();
+        assert_eq!(response.timestamp, 42);
+        assert_eq!(response.super_root, HASH);
+        assert_eq!(response.chains.len(), 1);
+        assert_eq!(response.chains[0].chain_id, 10);
+    }
+
+    #[tokio::test]
+    async fn test_all_safe_derived_at() {
+        let (client, _handle) = mock_supervisor().await;
+        let derived = client.all_safe_derived_at(BlockNumHash::new(5, HASH)).await.unwrap();
+        assert_eq!(derived.len(), 2);
+        assert_eq!(derived[&10], BlockNumHash::new(5, HASH));
+        assert_eq!(derived[&11], BlockNumHash::new(6, HASH));
     }
 }
@@ -0,0 +1,151 @@
+//! The [TransitionState] type.
+//!
+//! Represents the intermediate state of the interop fault proof program, while transitioning from
+//! one super root to the next.
+//!
+//! <https://specs.optimism.io/interop/fault-proof.html#transition-state>
+
+use crate::{SuperRoot, SuperRootResult, TransitionStateError, TRANSITION_STATE_VERSION};
+use alloc::vec::Vec;
+use alloy_primitives::{keccak256, Bytes, B256};
+use alloy_rlp::{Decodable, Encodable, RlpDecodable, RlpEncodable};
+
+/// A block derived optimistically for a chain while transitioning between super roots.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, RlpEncodable, RlpDecodable)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct OptimisticBlock {
+    /// The hash of the block.
+    pub block_hash: B256,
+    /// The output root of the block.
+    pub output_root: B256,
+}
+
+impl OptimisticBlock {
+    /// Create a new [OptimisticBlock] with the given block hash and output root.
+    pub const fn new(block_hash: B256, output_root: B256) -> Self {
+        Self { block_hash, output_root }
+    }
+}
+
+/// The [TransitionState] is the intermediate state between two super roots: the encoded pre-state
+/// [SuperRoot], the blocks derived so far for the next timestamp and the index of the step.
+#[derive(Debug, Clone, Default, Eq, PartialEq, RlpEncodable, RlpDecodable)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct TransitionState {
+    /// The encoded pre-state [SuperRoot].
+    pub pre_state: Bytes,
+    /// The optimistic blocks derived so far, one per chain in the order of the super root.
+    pub pending_progress: Vec<OptimisticBlock>,
+    /// The index of the step within the transition.
+    pub step: u64,
+}
+
+impl TransitionState {
+    /// Create a new [TransitionState] from the given pre-state [SuperRoot].
+    pub fn new(pre_state: &SuperRoot, pending_progress: Vec<OptimisticBlock>, step: u64) -> Self {
+        let mut buf = Vec::with_capacity(pre_state.encoded_length());
+        pre_state.encode(&mut buf);
+        Self { pre_state: buf.into(), pending_progress, step }
+    }
+
+    /// Decodes the pre-state [SuperRoot].
+    pub fn super_root(&self) -> SuperRootResult<SuperRoot> {
+        SuperRoot::decode(&mut self.pre_state.as_ref())
+    }
+
+    /// Decodes a [TransitionState] from the given buffer.
+    pub fn decode(buf: &mut &[u8]) -> Result<Self, TransitionStateError> {
+        let (&version, rest) = buf.split_first().ok_or(TransitionStateError::UnexpectedLength)?;
+        if version != TRANSITION_STATE_VERSION {
+            return Err(TransitionStateError::InvalidVersionByte);
+        }
+        *buf = rest;
+        <Self as Decodable>::decode(buf).map_err(TransitionStateError::Rlp)
+    }
+
+    /// Encode the [TransitionState] into the given buffer.
+    pub fn encode(&self, out: &mut dyn alloy_rlp::BufMut) {
+        out.put_u8(TRANSITION_STATE_VERSION);
+        Encodable::encode(self, out);
+    }
+
+    /// Returns the encoded length of the [TransitionState].
+    pub fn encoded_length(&self) -> usize {
+        1 + self.length()
+    }
+
+    /// Hashes the encoded [TransitionState] using [keccak256].
+    pub fn hash(&self) -> B256 {
+        let mut buf = Vec::with_capacity(self.encoded_length());
+        self.encode(&mut buf);
+        keccak256(&buf)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::OutputRootWithChain;
+    use alloc::vec;
+    use alloy_primitives::hex;
+
+    fn transition_state() -> TransitionState {
+        let super_root = SuperRoot::new(
+            10,
+            vec![OutputRootWithChain::new(1, B256::ZERO), OutputRootWithChain::new(2, B256::ZERO)],
+        );
+        TransitionState::new(
+            &super_root,
+            vec![OptimisticBlock::new(B256::with_last_byte(1), B256::with_last_byte(2))],
+            1,
+        )
+    }
+
+    #[test]
+    fn test_transition_state_roundtrip() {
+        let state = transition_state();
+        let mut buf = Vec::with_capacity(state.encoded_length());
+        state.encode(&mut buf);
+        assert_eq!(buf.len(), state.encoded_length());
+        assert_eq!(buf[0], TRANSITION_STATE_VERSION);
+        assert_eq!(TransitionState::decode(&mut buf.as_slice()).unwrap(), state);
+        assert_eq!(state.hash(), keccak256(&buf));
+    }
+
+    #[test]
+    fn test_transition_state_super_root() {
+        let state = transition_state();
+        let super_root = state.super_root().unwrap();
+        assert_eq!(super_root.timestamp, 10);
+        assert_eq!(super_root.output_roots.len(), 2);
+    }
+
+    #[test]
+    fn test_transition_state_encoding() {
+        let state = TransitionState {
+            pre_state: Bytes::from_static(&[0x01]),
+            pending_progress: vec![],
+            step: 2,
+        };
+        let mut buf = Vec::new();
+        state.encode(&mut buf);
+        // Version byte, followed by the RLP list of the pre-state, pending progress and step.
+        assert_eq!(buf, hex!("ffc301c002"));
+    }
+
+    #[test]
+    fn test_transition_state_decode_errors() {
+        assert_eq!(
+            TransitionState::decode(&mut [].as_slice()).unwrap_err(),
+            TransitionStateError::UnexpectedLength
+        );
+        assert_eq!(
+            TransitionState::decode(&mut [0x01, 0xc0].as_slice()).unwrap_err(),
+            TransitionStateError::InvalidVersionByte
+        );
+        assert!(matches!(
+            TransitionState::decode(&mut [TRANSITION_STATE_VERSION, 0xc3].as_slice()).unwrap_err(),
+            TransitionStateError::Rlp(_)
+        ));
+    }
+}
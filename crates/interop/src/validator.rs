@@ -0,0 +1,335 @@
+//! Contains the [LocalMessageValidator], validating [ExecutingMessage]s against an in-memory index
+//! of initiating logs.
+
+use crate::{ExecutingMessage, MessagePayload, MessageValidationError, MESSAGE_EXPIRY_WINDOW};
+use alloc::boxed::Box;
+use alloy_consensus::TxReceipt;
+use alloy_primitives::{keccak256, map::HashMap, Address, Log, B256, U256};
+
+/// An initiating log, as indexed by the [LocalMessageValidator].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InitiatingLog {
+    /// The address of the log emitter.
+    pub origin: Address,
+    /// The timestamp of the block containing the log.
+    pub timestamp: u64,
+    /// The hash of the [MessagePayload] of the log.
+    pub payload_hash: B256,
+}
+
+/// A supervisor-free validator of [ExecutingMessage]s, checking them against the initiating logs
+/// indexed per chain.
+#[derive(Debug, Clone)]
+pub struct LocalMessageValidator {
+    /// The indexed initiating logs, by chain ID, block number and log index.
+    logs: HashMap<(u64, u64, u64), InitiatingLog>,
+    /// The expiry window of initiating messages, in seconds.
+    expiry_window: u64,
+}
+
+impl Default for LocalMessageValidator {
+    fn default() -> Self {
+        Self::new(MESSAGE_EXPIRY_WINDOW)
+    }
+}
+
+impl LocalMessageValidator {
+    /// Creates a new [LocalMessageValidator] with the given expiry window, e.g. the
+    /// `interop_message_expiry_window` of the rollup config. The window is capped to the protocol
+    /// [MESSAGE_EXPIRY_WINDOW].
+    pub fn new(expiry_window: u64) -> Self {
+        Self { logs: HashMap::default(), expiry_window: expiry_window.min(MESSAGE_EXPIRY_WINDOW) }
+    }
+
+    /// Returns the expiry window of initiating messages, in seconds.
+    pub const fn expiry_window(&self) -> u64 {
+        self.expiry_window
+    }
+
+    /// Indexes a log of the given chain and block as an initiating log.
+    pub fn index_log(
+        &mut self,
+        chain_id: u64,
+        block_number: u64,
+        log_index: u64,
+        timestamp: u64,
+        log: &Log,
+    ) {
+        let payload_hash = keccak256(MessagePayload::from(log).as_ref());
+        self.logs.insert(
+            (chain_id, block_number, log_index),
+            InitiatingLog { origin: log.address, timestamp, payload_hash },
+        );
+    }
+
+    /// Indexes all the logs of the receipts of a block, numbered by their position in the block.
+    pub fn index_receipts(
+        &mut self,
+        chain_id: u64,
+        block_number: u64,
+        timestamp: u64,
+        receipts: &[impl TxReceipt<Log = Log>],
+    ) {
+        let logs = receipts.iter().flat_map(|receipt| receipt.logs());
+        for (log_index, log) in logs.enumerate() {
+            self.index_log(chain_id, block_number, log_index as u64, timestamp, log);
+        }
+    }
+
+    /// Returns the indexed initiating log at the given position, if any.
+    pub fn log(&self, chain_id: u64, block_number: u64, log_index: u64) -> Option<&InitiatingLog> {
+        self.logs.get(&(chain_id, block_number, log_index))
+    }
+
+    /// Validates an [ExecutingMessage] emitted at the given log of the executing block.
+    ///
+    /// Messages initiated in the executing block itself must reference a log before the
+    /// executing log.
+    pub fn validate_message(
+        &self,
+        message: &ExecutingMessage,
+        executing_chain_id: u64,
+        executing_block_number: u64,
+        executing_log_index: u64,
+        executing_timestamp: u64,
+    ) -> Result<(), MessageValidationError> {
+        let id = &message.id;
+        let log = match (
+            u64::try_from(id.chainId),
+            u64::try_from(id.blockNumber),
+            u64::try_from(id.logIndex),
+        ) {
+            (Ok(chain_id), Ok(block_number), Ok(log_index)) => self
+                .log(chain_id, block_number, log_index)
+                .map(|log| (chain_id, block_number, log_index, log)),
+            _ => None,
+        }
+        .ok_or_else(|| MessageValidationError::UnknownMessage(Box::new(id.clone())))?;
+        let (chain_id, block_number, log_index, log) = log;
+
+        if log.origin != id.origin {
+            return Err(MessageValidationError::OriginMismatch {
+                expected: log.origin,
+                got: id.origin,
+            });
+        }
+        if id.timestamp != U256::from(log.timestamp) {
+            return Err(MessageValidationError::TimestampMismatch {
+                expected: log.timestamp,
+                got: id.timestamp.saturating_to(),
+            });
+        }
+        if log.payload_hash != message.msgHash {
+            return Err(MessageValidationError::PayloadHashMismatch {
+                expected: log.payload_hash,
+                got: message.msgHash,
+            });
+        }
+        if log.timestamp > executing_timestamp {
+            return Err(MessageValidationError::InitiatedAfterExecution {
+                initiated_at: log.timestamp,
+                executed_at: executing_timestamp,
+            });
+        }
+        if chain_id == executing_chain_id
+            && block_number == executing_block_number
+            && log_index >= executing_log_index
+        {
+            return Err(MessageValidationError::InitiatedAfterExecutingLog {
+                initiating_log_index: log_index,
+                executing_log_index,
+            });
+        }
+        if executing_timestamp > log.timestamp.saturating_add(self.expiry_window) {
+            return Err(MessageValidationError::Expired {
+                initiated_at: log.timestamp,
+                executed_at: executing_timestamp,
+            });
+        }
+        Ok(())
+    }
+
+    /// Validates all the [ExecutingMessage]s of a block, each paired with the index of its
+    /// executing log, returning the first error.
+    pub fn validate_messages(
+        &self,
+        messages: &[(u64, ExecutingMessage)],
+        executing_chain_id: u64,
+        executing_block_number: u64,
+        executing_timestamp: u64,
+    ) -> Result<(), MessageValidationError> {
+        messages.iter().try_for_each(|(log_index, message)| {
+            self.validate_message(
+                message,
+                executing_chain_id,
+                executing_block_number,
+                *log_index,
+                executing_timestamp,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MessageIdentifier;
+    use alloc::vec;
+    use alloy_consensus::Receipt;
+    use alloy_primitives::{address, b256, Bytes, LogData};
+
+    const CHAIN_ID: u64 = 10;
+    const EXECUTING_CHAIN_ID: u64 = 11;
+    const TIMESTAMP: u64 = 1_000;
+    const ORIGIN: Address = address!("6887246668a3b87F54DeB3b94Ba47a6f63F32985");
+
+    fn log(data: &'static [u8]) -> Log {
+        Log {
+            address: ORIGIN,
+            data: LogData::new_unchecked(
+                vec![b256!("0000000000000000000000000000000000000000000000000000000000000001")],
+                Bytes::from_static(data),
+            ),
+        }
+    }
+
+    fn validator() -> LocalMessageValidator {
+        let mut validator = LocalMessageValidator::default();
+        let receipts = [
+            Receipt { logs: vec![log(b"first")], ..Default::default() },
+            Receipt { logs: vec![log(b"second"), log(b"third")], ..Default::default() },
+        ];
+        validator.index_receipts(CHAIN_ID, 5, TIMESTAMP, &receipts);
+        validator
+    }
+
+    fn message(log_index: u64, data: &'static [u8]) -> ExecutingMessage {
+        ExecutingMessage {
+            msgHash: keccak256(MessagePayload::from(&log(data)).as_ref()),
+            id: MessageIdentifier {
+                origin: ORIGIN,
+                blockNumber: U256::from(5),
+                logIndex: U256::from(log_index),
+                timestamp: U256::from(TIMESTAMP),
+                chainId: U256::from(CHAIN_ID),
+            },
+        }
+    }
+
+    #[test]
+    fn test_index_receipts() {
+        let validator = validator();
+        assert_eq!(validator.log(CHAIN_ID, 5, 2).unwrap().origin, ORIGIN);
+        assert!(validator.log(CHAIN_ID, 5, 3).is_none());
+        assert!(validator.log(CHAIN_ID + 1, 5, 0).is_none());
+    }
+
+    #[test]
+    fn test_validate_messages() {
+        let validator = validator();
+        let messages = [(0, message(0, b"first")), (1, message(2, b"third"))];
+        assert_eq!(
+            validator.validate_messages(&messages, EXECUTING_CHAIN_ID, 1, TIMESTAMP),
+            Ok(())
+        );
+        assert_eq!(
+            validator.validate_messages(
+                &messages,
+                EXECUTING_CHAIN_ID,
+                1,
+                TIMESTAMP + MESSAGE_EXPIRY_WINDOW
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_validate_message_unknown() {
+        let msg = message(3, b"third");
+        assert_eq!(
+            validator().validate_message(&msg, EXECUTING_CHAIN_ID, 1, 0, TIMESTAMP),
+            Err(MessageValidationError::UnknownMessage(Box::new(msg.id)))
+        );
+    }
+
+    #[test]
+    fn test_validate_message_mismatches() {
+        let validator = validator();
+
+        let mut msg = message(1, b"second");
+        msg.id.origin = Address::ZERO;
+        assert_eq!(
+            validator.validate_message(&msg, EXECUTING_CHAIN_ID, 1, 0, TIMESTAMP),
+            Err(MessageValidationError::OriginMismatch { expected: ORIGIN, got: Address::ZERO })
+        );
+
+        let mut msg = message(1, b"second");
+        msg.id.timestamp = U256::from(TIMESTAMP + 2);
+        assert_eq!(
+            validator.validate_message(&msg, EXECUTING_CHAIN_ID, 1, 0, TIMESTAMP),
+            Err(MessageValidationError::TimestampMismatch {
+                expected: TIMESTAMP,
+                got: TIMESTAMP + 2
+            })
+        );
+
+        let msg = message(1, b"third");
+        assert_eq!(
+            validator.validate_message(&msg, EXECUTING_CHAIN_ID, 1, 0, TIMESTAMP),
+            Err(MessageValidationError::PayloadHashMismatch {
+                expected: keccak256(MessagePayload::from(&log(b"second")).as_ref()),
+                got: msg.msgHash,
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_message_timing() {
+        let validator = LocalMessageValidator { expiry_window: 100, ..validator() };
+        let msg = message(0, b"first");
+        assert_eq!(
+            validator.validate_message(&msg, EXECUTING_CHAIN_ID, 1, 0, TIMESTAMP - 2),
+            Err(MessageValidationError::InitiatedAfterExecution {
+                initiated_at: TIMESTAMP,
+                executed_at: TIMESTAMP - 2,
+            })
+        );
+        assert_eq!(
+            validator.validate_message(&msg, EXECUTING_CHAIN_ID, 1, 0, TIMESTAMP + 100),
+            Ok(())
+        );
+        assert_eq!(
+            validator.validate_message(&msg, EXECUTING_CHAIN_ID, 1, 0, TIMESTAMP + 101),
+            Err(MessageValidationError::Expired {
+                initiated_at: TIMESTAMP,
+                executed_at: TIMESTAMP + 101,
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_message_same_block() {
+        let validator = validator();
+        let msg = message(1, b"second");
+        assert_eq!(validator.validate_message(&msg, CHAIN_ID, 5, 2, TIMESTAMP), Ok(()));
+        for executing_log_index in [0, 1] {
+            assert_eq!(
+                validator.validate_message(&msg, CHAIN_ID, 5, executing_log_index, TIMESTAMP),
+                Err(MessageValidationError::InitiatedAfterExecutingLog {
+                    initiating_log_index: 1,
+                    executing_log_index,
+                })
+            );
+        }
+
+        // The same position in another block or on another chain is not the same log.
+        assert_eq!(validator.validate_message(&msg, CHAIN_ID, 6, 0, TIMESTAMP), Ok(()));
+        assert_eq!(validator.validate_message(&msg, EXECUTING_CHAIN_ID, 5, 0, TIMESTAMP), Ok(()));
+    }
+
+    #[test]
+    fn test_expiry_window_capped() {
+        assert_eq!(LocalMessageValidator::new(u64::MAX).expiry_window(), MESSAGE_EXPIRY_WINDOW);
+        assert_eq!(LocalMessageValidator::new(3600).expiry_window(), 3600);
+    }
+}
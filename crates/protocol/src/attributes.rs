@@ -0,0 +1,549 @@
+//! Contains the [AttributesBuilder], which derives the [OpPayloadAttributes] of the next L2 block.
+
+use crate::{
+    derive_deposits, BlockInfoError, DepositError, Ecotone, Fjord, Interop, Isthmus, L1BlockInfoTx,
+    L2BlockInfo, Predeploys,
+};
+use alloc::vec::Vec;
+use alloy_consensus::{Header, Receipt};
+use alloy_eips::{eip2718::Encodable2718, BlockNumHash};
+use alloy_primitives::{Bytes, B256};
+use alloy_rpc_types_engine::PayloadAttributes;
+use maili_genesis::{OpHardfork, RollupConfig, SystemConfig, SystemConfigUpdateError};
+use op_alloy_rpc_types_engine::OpPayloadAttributes;
+
+/// An error returned by the [AttributesBuilder].
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum AttributesBuilderError {
+    /// The L1 origin is neither the L1 origin of the parent block, nor its child.
+    #[error("L1 origin {l1_origin:?} does not follow the parent's L1 origin {parent_origin:?}")]
+    L1OriginMismatch {
+        /// The L1 origin of the parent L2 block.
+        parent_origin: BlockNumHash,
+        /// The L1 origin given for the next L2 block.
+        l1_origin: BlockNumHash,
+    },
+    /// The L2 block timestamp is before the timestamp of its L1 origin.
+    #[error("L2 block timestamp {l2_timestamp} is before the L1 origin timestamp {l1_timestamp}")]
+    BrokenTimeInvariant {
+        /// The timestamp of the L1 origin.
+        l1_timestamp: u64,
+        /// The timestamp of the next L2 block.
+        l2_timestamp: u64,
+    },
+    /// Failed to apply a system config update from the L1 receipts.
+    #[error("Failed to update the system config: {0}")]
+    SystemConfigUpdate(#[from] SystemConfigUpdateError),
+    /// Failed to derive a user deposit from the L1 receipts.
+    #[error("Failed to derive deposits: {0}")]
+    Deposit(#[from] DepositError),
+    /// Failed to build the L1 info deposit transaction.
+    #[error("Failed to build the L1 info transaction: {0}")]
+    L1BlockInfo(#[from] BlockInfoError),
+}
+
+/// The [AttributesBuilder] derives the [OpPayloadAttributes] of the next L2 block from its parent
+/// and its L1 origin.
+///
+/// The payload attributes hold the L1 info deposit transaction, followed by the user deposits
+/// of the L1 origin when it starts a new epoch, and the network upgrade transactions when the
+/// block activates a hardfork. Transactions from batches are not included, and are appended by
+/// the caller.
+#[derive(Debug, Clone, Copy)]
+pub struct AttributesBuilder<'a> {
+    /// The rollup config.
+    rollup_config: &'a RollupConfig,
+}
+
+impl<'a> AttributesBuilder<'a> {
+    /// Creates a new [AttributesBuilder] for the given rollup config.
+    pub const fn new(rollup_config: &'a RollupConfig) -> Self {
+        Self { rollup_config }
+    }
+
+    /// Builds the [OpPayloadAttributes] of the L2 block following `parent`, with the given L1
+    /// origin.
+    ///
+    /// The L1 origin must either be the L1 origin of the parent block, or its child. When the L1
+    /// origin starts a new epoch, the `system_config` is updated with the config updates emitted
+    /// in the `l1_receipts`, and the user deposits are derived from them. Otherwise, the receipts
+    /// are ignored.
+    ///
+    /// The `system_config` must be the system config of the parent block. It is only updated if
+    /// the attributes are built successfully.
+    pub fn prepare_payload_attributes(
+        &self,
+        parent: &L2BlockInfo,
+        system_config: &mut SystemConfig,
+        l1_header: &Header,
+        l1_receipts: &[Receipt],
+    ) -> Result<OpPayloadAttributes, AttributesBuilderError> {
+        let cfg = self.rollup_config;
+        let mut sys_config = *system_config;
+        let l1_origin = BlockNumHash { number: l1_header.number, hash: l1_header.hash_slow() };
+
+        let (sequence_number, deposits) = if parent.l1_origin.number == l1_origin.number {
+            // The parent's epoch continues, without any new deposits.
+            if parent.l1_origin.hash != l1_origin.hash {
+                return Err(AttributesBuilderError::L1OriginMismatch {
+                    parent_origin: parent.l1_origin,
+                    l1_origin,
+                });
+            }
+            (parent.seq_num + 1, Vec::new())
+        } else {
+            // A new epoch starts, so apply the system config updates and derive the deposits.
+            if parent.l1_origin.number + 1 != l1_origin.number
+                || parent.l1_origin.hash != l1_header.parent_hash
+            {
+                return Err(AttributesBuilderError::L1OriginMismatch {
+                    parent_origin: parent.l1_origin,
+                    l1_origin,
+                });
+            }
+            sys_config.update_with_receipts(
+                l1_receipts,
+                cfg.l1_system_config_address,
+                cfg.is_ecotone_active(l1_header.timestamp),
+            )?;
+            let deposits =
+                derive_deposits(l1_origin.hash, l1_receipts, cfg.deposit_contract_address)?;
+            (0, deposits)
+        };
+
+        let next_timestamp = parent.block_info.timestamp + cfg.block_time;
+        if next_timestamp < l1_header.timestamp {
+            return Err(AttributesBuilderError::BrokenTimeInvariant {
+                l1_timestamp: l1_header.timestamp,
+                l2_timestamp: next_timestamp,
+            });
+        }
+
+        let (_, l1_info_tx) = L1BlockInfoTx::try_new_with_deposit_tx(
+            cfg,
+            &sys_config,
+            sequence_number,
+            l1_header,
+            next_timestamp,
+        )?;
+
+        let mut transactions = Vec::with_capacity(1 + deposits.len());
+        transactions.push(Bytes::from(l1_info_tx.encoded_2718()));
+        transactions.extend(deposits);
+        transactions.extend(upgrade_txs(cfg, next_timestamp));
+
+        let withdrawals = cfg.is_canyon_active(next_timestamp).then(Vec::new);
+        let parent_beacon_block_root = cfg
+            .is_ecotone_active(next_timestamp)
+            .then(|| l1_header.parent_beacon_block_root.unwrap_or(B256::ZERO));
+
+        *system_config = sys_config;
+        Ok(OpPayloadAttributes {
+            payload_attributes: PayloadAttributes {
+                timestamp: next_timestamp,
+                prev_randao: l1_header.mix_hash,
+                suggested_fee_recipient: Predeploys::SEQUENCER_FEE_VAULT,
+                withdrawals,
+                parent_beacon_block_root,
+            },
+            transactions: Some(transactions),
+            no_tx_pool: Some(true),
+            gas_limit: Some(sys_config.gas_limit),
+            eip_1559_params: sys_config.eip_1559_params(
+                cfg,
+                parent.block_info.timestamp,
+                next_timestamp,
+            ),
+        })
+    }
+}
+
+/// Returns the network upgrade transactions of the hardforks activated by the L2 block at the
+/// given timestamp, in the order they are included after the user deposits.
+fn upgrade_txs(cfg: &RollupConfig, timestamp: u64) -> Vec<Bytes> {
+    let mut txs = Vec::new();
+    if cfg.is_first_fork_block(OpHardfork::Ecotone, timestamp) {
+        txs.extend(Ecotone::txs());
+    }
+    if cfg.is_first_fork_block(OpHardfork::Fjord, timestamp) {
+        txs.extend(Fjord::txs());
+    }
+    if cfg.is_first_fork_block(OpHardfork::Isthmus, timestamp) {
+        txs.extend(Isthmus::txs());
+    }
+    if cfg.is_first_fork_block(OpHardfork::Interop, timestamp) {
+        txs.extend(Interop::txs());
+    }
+    txs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decode_deposit, BlockInfo, DEPOSIT_EVENT_ABI_HASH};
+    use alloc::vec;
+    use alloy_consensus::Eip658Value;
+    use alloy_eips::eip2718::Decodable2718;
+    use alloy_primitives::{address, b256, Address, Log, LogData, B64, U256, U64};
+    use maili_genesis::{CONFIG_UPDATE_EVENT_VERSION_0, CONFIG_UPDATE_TOPIC};
+    use op_alloy_consensus::TxDeposit;
+
+    const DEPOSIT_CONTRACT: Address = address!("bEb5Fc579115071764c7423A4f12eDde41f106Ed");
+
+    fn rollup_config() -> RollupConfig {
+        RollupConfig {
+            block_time: 2,
+            deposit_contract_address: DEPOSIT_CONTRACT,
+            regolith_time: Some(0),
+            ..Default::default()
+        }
+    }
+
+    fn system_config() -> SystemConfig {
+        SystemConfig {
+            gas_limit: 30_000_000,
+            eip1559_denominator: Some(250),
+            eip1559_elasticity: Some(6),
+            ..Default::default()
+        }
+    }
+
+    fn l1_header(number: u64, timestamp: u64, parent_hash: B256) -> Header {
+        Header {
+            number,
+            timestamp,
+            parent_hash,
+            mix_hash: B256::repeat_byte(0xAA),
+            parent_beacon_block_root: Some(B256::repeat_byte(0xBB)),
+            ..Default::default()
+        }
+    }
+
+    fn parent(l1_origin: &Header, timestamp: u64, seq_num: u64) -> L2BlockInfo {
+        L2BlockInfo {
+            block_info: BlockInfo { number: 10, timestamp, ..Default::default() },
+            l1_origin: BlockNumHash { number: l1_origin.number, hash: l1_origin.hash_slow() },
+            seq_num,
+        }
+    }
+
+    fn deposit_receipt() -> Receipt {
+        let from = b256!("000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF");
+        let to = b256!("000000000000000000000000bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb");
+        let mut data = vec![0u8; 192];
+        data[24..32].copy_from_slice(&U64::from(32).to_be_bytes::<8>());
+        data[56..64].copy_from_slice(&U64::from(128).to_be_bytes::<8>());
+        let log = Log {
+            address: DEPOSIT_CONTRACT,
+            data: LogData::new_unchecked(
+                vec![DEPOSIT_EVENT_ABI_HASH, from, to, B256::ZERO],
+                Bytes::from(data),
+            ),
+        };
+        Receipt { status: Eip658Value::Eip658(true), cumulative_gas_used: 0, logs: vec![log] }
+    }
+
+    fn gas_limit_update_receipt(gas_limit: u64) -> Receipt {
+        let mut data = vec![0u8; 96];
+        data[31] = 32;
+        data[63] = 32;
+        data[88..96].copy_from_slice(&gas_limit.to_be_bytes());
+        let log = Log {
+            address: Address::ZERO,
+            data: LogData::new_unchecked(
+                vec![CONFIG_UPDATE_TOPIC, CONFIG_UPDATE_EVENT_VERSION_0, B256::with_last_byte(2)],
+                Bytes::from(data),
+            ),
+        };
+        Receipt { status: Eip658Value::Eip658(true), cumulative_gas_used: 0, logs: vec![log] }
+    }
+
+    fn l1_info(attributes: &OpPayloadAttributes) -> L1BlockInfoTx {
+        let txs = attributes.transactions.as_ref().unwrap();
+        let tx = TxDeposit::decode_2718(&mut txs[0].as_ref()).unwrap();
+        L1BlockInfoTx::decode_calldata(&tx.input).unwrap()
+    }
+
+    #[test]
+    fn test_prepare_payload_attributes_same_epoch() {
+        let cfg = rollup_config();
+        let origin = l1_header(100, 1_000, B256::ZERO);
+        let parent = parent(&origin, 1_004, 2);
+        let mut sys_config = system_config();
+
+        let attributes = AttributesBuilder::new(&cfg)
+            .prepare_payload_attributes(&parent, &mut sys_config, &origin, &[deposit_receipt()])
+            .unwrap();
+
+        // The receipts are ignored within an epoch.
+        assert_eq!(attributes.transactions.as_ref().unwrap().len(), 1);
+        assert_eq!(l1_info(&attributes).sequence_number(), 3);
+        assert_eq!(l1_info(&attributes).id(), parent.l1_origin);
+        assert_eq!(attributes.payload_attributes.timestamp, 1_006);
+        assert_eq!(attributes.payload_attributes.prev_randao, origin.mix_hash);
+        assert_eq!(
+            attributes.payload_attributes.suggested_fee_recipient,
+            Predeploys::SEQUENCER_FEE_VAULT
+        );
+        assert_eq!(attributes.payload_attributes.withdrawals, None);
+        assert_eq!(attributes.payload_attributes.parent_beacon_block_root, None);
+        assert_eq!(attributes.no_tx_pool, Some(true));
+        assert_eq!(attributes.gas_limit, Some(30_000_000));
+        assert_eq!(attributes.eip_1559_params, None);
+    }
+
+    #[test]
+    fn test_prepare_payload_attributes_new_epoch() {
+        let cfg = rollup_config();
+        let prev_origin = l1_header(100, 1_000, B256::ZERO);
+        let origin = l1_header(101, 1_012, prev_origin.hash_slow());
+        let parent = parent(&prev_origin, 1_012, 5);
+        let mut sys_config = system_config();
+
+        let attributes = AttributesBuilder::new(&cfg)
+            .prepare_payload_attributes(&parent, &mut sys_config, &origin, &[deposit_receipt()])
+            .unwrap();
+
+        let txs = attributes.transactions.as_ref().unwrap();
+        assert_eq!(txs.len(), 2);
+        assert_eq!(l1_info(&attributes).sequence_number(), 0);
+        assert_eq!(l1_info(&attributes).block_hash(), origin.hash_slow());
+        assert_eq!(
+            txs[1],
+            decode_deposit(origin.hash_slow(), 0, &deposit_receipt().logs[0]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_prepare_payload_attributes_l1_origin_mismatch() {
+        let cfg = rollup_config();
+        let prev_origin = l1_header(100, 1_000, B256::ZERO);
+        let parent = parent(&prev_origin, 1_012, 5);
+        let builder = AttributesBuilder::new(&cfg);
+
+        // Same number, different hash.
+        let origin = l1_header(100, 1_001, B256::ZERO);
+        let err = builder
+            .prepare_payload_attributes(&parent, &mut system_config(), &origin, &[])
+            .unwrap_err();
+        assert_eq!(
+            err,
+            AttributesBuilderError::L1OriginMismatch {
+                parent_origin: parent.l1_origin,
+                l1_origin: BlockNumHash { number: 100, hash: origin.hash_slow() },
+            }
+        );
+
+        // Next number, not a child of the parent's L1 origin.
+        let origin = l1_header(101, 1_012, B256::ZERO);
+        assert!(matches!(
+            builder.prepare_payload_attributes(&parent, &mut system_config(), &origin, &[]),
+            Err(AttributesBuilderError::L1OriginMismatch { .. })
+        ));
+
+        // Skipped L1 block.
+        let origin = l1_header(102, 1_012, prev_origin.hash_slow());
+        assert!(matches!(
+            builder.prepare_payload_attributes(&parent, &mut system_config(), &origin, &[]),
+            Err(AttributesBuilderError::L1OriginMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_prepare_payload_attributes_broken_time_invariant() {
+        let cfg = rollup_config();
+        let prev_origin = l1_header(100, 1_000, B256::ZERO);
+        let origin = l1_header(101, 1_020, prev_origin.hash_slow());
+        let parent = parent(&prev_origin, 1_012, 5);
+
+        let err = AttributesBuilder::new(&cfg)
+            .prepare_payload_attributes(&parent, &mut system_config(), &origin, &[])
+            .unwrap_err();
+        assert_eq!(
+            err,
+            AttributesBuilderError::BrokenTimeInvariant {
+                l1_timestamp: 1_020,
+                l2_timestamp: 1_014
+            }
+        );
+    }
+
+    #[test]
+    fn test_prepare_payload_attributes_system_config_update() {
+        let cfg = rollup_config();
+        let prev_origin = l1_header(100, 1_000, B256::ZERO);
+        let origin = l1_header(101, 1_012, prev_origin.hash_slow());
+        let parent = parent(&prev_origin, 1_012, 5);
+        let mut sys_config = system_config();
+
+        let attributes = AttributesBuilder::new(&cfg)
+            .prepare_payload_attributes(
+                &parent,
+                &mut sys_config,
+                &origin,
+                &[gas_limit_update_receipt(60_000_000)],
+            )
+            .unwrap();
+        assert_eq!(attributes.gas_limit, Some(60_000_000));
+        assert_eq!(sys_config.gas_limit, 60_000_000);
+
+        // The system config is left untouched if the attributes fail to build.
+        let origin = l1_header(101, 1_020, prev_origin.hash_slow());
+        let mut sys_config = system_config();
+        assert!(matches!(
+            AttributesBuilder::new(&cfg).prepare_payload_attributes(
+                &parent,
+                &mut sys_config,
+                &origin,
+                &[gas_limit_update_receipt(60_000_000)],
+            ),
+            Err(AttributesBuilderError::BrokenTimeInvariant { .. })
+        ));
+        assert_eq!(sys_config, system_config());
+    }
+
+    #[test]
+    fn test_prepare_payload_attributes_invalid_deposit() {
+        let cfg = rollup_config();
+        let prev_origin = l1_header(100, 1_000, B256::ZERO);
+        let origin = l1_header(101, 1_012, prev_origin.hash_slow());
+        let parent = parent(&prev_origin, 1_012, 5);
+        let mut receipt = deposit_receipt();
+        receipt.logs[0].data =
+            LogData::new_unchecked(receipt.logs[0].topics().to_vec(), Bytes::from(vec![0u8; 63]));
+
+        let err = AttributesBuilder::new(&cfg)
+            .prepare_payload_attributes(&parent, &mut system_config(), &origin, &[receipt])
+            .unwrap_err();
+        assert_eq!(err, AttributesBuilderError::Deposit(DepositError::IncompleteOpaqueData(63)));
+    }
+
+    #[test]
+    fn test_prepare_payload_attributes_post_ecotone() {
+        let cfg = RollupConfig { canyon_time: Some(0), ecotone_time: Some(0), ..rollup_config() };
+        let origin = l1_header(100, 1_000, B256::ZERO);
+        let parent = parent(&origin, 1_004, 0);
+
+        let attributes = AttributesBuilder::new(&cfg)
+            .prepare_payload_attributes(&parent, &mut system_config(), &origin, &[])
+            .unwrap();
+        assert_eq!(attributes.payload_attributes.withdrawals, Some(Vec::new()));
+        assert_eq!(
+            attributes.payload_attributes.parent_beacon_block_root,
+            Some(B256::repeat_byte(0xBB))
+        );
+        assert!(matches!(l1_info(&attributes), L1BlockInfoTx::Ecotone(_)));
+    }
+
+    /// Builds the attributes of the new epoch block at timestamp `1_014`, and returns its
+    /// transactions after the L1 info transaction and the single user deposit.
+    fn upgrade_txs(cfg: &RollupConfig) -> Vec<Bytes> {
+        let prev_origin = l1_header(100, 1_000, B256::ZERO);
+        let origin = l1_header(101, 1_012, prev_origin.hash_slow());
+        let parent = parent(&prev_origin, 1_012, 5);
+
+        let attributes = AttributesBuilder::new(cfg)
+            .prepare_payload_attributes(
+                &parent,
+                &mut system_config(),
+                &origin,
+                &[deposit_receipt()],
+            )
+            .unwrap();
+        let txs = attributes.transactions.unwrap();
+        assert_eq!(
+            txs[1],
+            decode_deposit(origin.hash_slow(), 0, &deposit_receipt().logs[0]).unwrap()
+        );
+        txs[2..].to_vec()
+    }
+
+    #[test]
+    fn test_prepare_payload_attributes_ecotone_activation() {
+        let cfg = RollupConfig { ecotone_time: Some(1_014), ..rollup_config() };
+        assert_eq!(upgrade_txs(&cfg), Ecotone::txs().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_prepare_payload_attributes_fjord_activation() {
+        let cfg =
+            RollupConfig { ecotone_time: Some(0), fjord_time: Some(1_014), ..rollup_config() };
+        assert_eq!(upgrade_txs(&cfg), Fjord::txs().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_prepare_payload_attributes_isthmus_activation() {
+        let cfg = RollupConfig {
+            ecotone_time: Some(0),
+            fjord_time: Some(0),
+            isthmus_time: Some(1_014),
+            ..rollup_config()
+        };
+        assert_eq!(upgrade_txs(&cfg), Isthmus::txs().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_prepare_payload_attributes_interop_activation() {
+        let cfg = RollupConfig {
+            ecotone_time: Some(0),
+            fjord_time: Some(0),
+            isthmus_time: Some(0),
+            interop_time: Some(1_014),
+            ..rollup_config()
+        };
+        assert_eq!(upgrade_txs(&cfg), Interop::txs().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_prepare_payload_attributes_simultaneous_activations() {
+        let cfg =
+            RollupConfig { ecotone_time: Some(1_014), fjord_time: Some(1_014), ..rollup_config() };
+        let expected = Ecotone::txs().chain(Fjord::txs()).collect::<Vec<_>>();
+        assert_eq!(upgrade_txs(&cfg), expected);
+
+        // Blocks after the activation block have no upgrade transactions.
+        let cfg = RollupConfig { ecotone_time: Some(1_012), ..rollup_config() };
+        assert!(upgrade_txs(&cfg).is_empty());
+    }
+
+    #[test]
+    fn test_prepare_payload_attributes_holocene_eip1559_params() {
+        let cfg = RollupConfig { holocene_time: Some(1_006), ..rollup_config() };
+        let origin = l1_header(100, 1_000, B256::ZERO);
+        let builder = AttributesBuilder::new(&cfg);
+
+        // Before Holocene, no EIP-1559 params are set.
+        let attributes = builder
+            .prepare_payload_attributes(
+                &parent(&origin, 1_002, 0),
+                &mut system_config(),
+                &origin,
+                &[],
+            )
+            .unwrap();
+        assert_eq!(attributes.eip_1559_params, None);
+
+        // The first Holocene block signals the default params.
+        let attributes = builder
+            .prepare_payload_attributes(
+                &parent(&origin, 1_004, 1),
+                &mut system_config(),
+                &origin,
+                &[],
+            )
+            .unwrap();
+        assert_eq!(attributes.eip_1559_params, Some(B64::ZERO));
+
+        // Later blocks use the system config params.
+        let attributes = builder
+            .prepare_payload_attributes(
+                &parent(&origin, 1_006, 2),
+                &mut system_config(),
+                &origin,
+                &[],
+            )
+            .unwrap();
+        assert_eq!(attributes.eip_1559_params, Some(B64::from_slice(&[0, 0, 0, 250, 0, 0, 0, 6])));
+        assert_eq!(l1_info(&attributes).l1_fee_overhead(), U256::ZERO);
+    }
+}
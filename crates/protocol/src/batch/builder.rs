@@ -0,0 +1,406 @@
+//! Contains the [SpanBatchBuilder], which accumulates [SingleBatch]es into a span batch.
+
+use crate::{
+    RawSpanBatch, SingleBatch, SpanBatch, SpanBatchBuilderError, SpanBatchError,
+    SpanBatchTransactions, MAX_SPAN_BATCH_ELEMENTS,
+};
+
+/// The encoded length of the parent check and L1 origin check in the span batch prefix.
+const PREFIX_CHECKS_LEN: usize = 20 + 20;
+
+/// The encoded length of a transaction signature, excluding the y-parity bit.
+const TX_SIG_LEN: usize = 32 + 32;
+
+/// The encoded length of a transaction `to` address.
+const TX_TO_LEN: usize = 20;
+
+/// Returns the length of the unsigned varint encoding of `value`.
+fn varint_len(value: u64) -> usize {
+    let mut buf = [0u8; 10];
+    unsigned_varint::encode::u64(value, &mut buf).len()
+}
+
+/// Returns the length of a span batch bitlist holding `bits` bits.
+const fn bits_len(bits: usize) -> usize {
+    bits / 8 + if bits % 8 != 0 { 1 } else { 0 }
+}
+
+/// The running totals of a span batch that determine its encoded size.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct SpanBatchSize {
+    /// The number of blocks.
+    blocks: usize,
+    /// The total number of transactions.
+    txs: usize,
+    /// The number of legacy transactions.
+    legacy_txs: usize,
+    /// The encoded length of the block transaction counts.
+    tx_counts_len: usize,
+    /// The encoded length of the per-transaction fields: signatures, `to` addresses, transaction
+    /// data, nonces and gas limits.
+    tx_fields_len: usize,
+}
+
+impl SpanBatchSize {
+    /// Returns the totals with a block holding the given transactions added.
+    fn with_block(self, txs: &SpanBatchTransactions) -> Self {
+        let tx_fields_len = txs.tx_sigs.len() * TX_SIG_LEN
+            + txs.tx_tos.len() * TX_TO_LEN
+            + txs.tx_datas.iter().map(|d| d.len()).sum::<usize>()
+            + txs.tx_nonces.iter().map(|n| varint_len(*n)).sum::<usize>()
+            + txs.tx_gases.iter().map(|g| varint_len(*g)).sum::<usize>();
+        Self {
+            blocks: self.blocks + 1,
+            txs: self.txs + txs.total_block_tx_count as usize,
+            legacy_txs: self.legacy_txs + txs.legacy_tx_count as usize,
+            tx_counts_len: self.tx_counts_len + varint_len(txs.total_block_tx_count),
+            tx_fields_len: self.tx_fields_len + tx_fields_len,
+        }
+    }
+
+    /// Returns the encoded length of the span batch, including the batch type byte.
+    fn encoded_len(&self, rel_timestamp: u64, l1_origin_num: u64) -> usize {
+        let prefix_len = varint_len(rel_timestamp) + varint_len(l1_origin_num) + PREFIX_CHECKS_LEN;
+        let payload_len = varint_len(self.blocks as u64)
+            + bits_len(self.blocks)
+            + self.tx_counts_len
+            // Contract creation bits and y-parity bits.
+            + 2 * bits_len(self.txs)
+            + self.tx_fields_len
+            + bits_len(self.legacy_txs);
+        1 + prefix_len + payload_len
+    }
+}
+
+/// The [SpanBatchBuilder] accumulates [SingleBatch]es into a [SpanBatch] on the batcher side.
+///
+/// Unlike [SpanBatch::append_singular_batch], the builder rejects batches that are out of order,
+/// and tracks the encoded size of the span batch as it grows. Once adding the next batch would
+/// push the span batch past the target size, [Self::add_batch] returns
+/// [SpanBatchBuilderError::TargetSizeExceeded] without modifying the builder, signalling that the
+/// span batch should be [finished](Self::finish) and submitted. The first batch is always
+/// accepted, so that a single oversized block does not stall the batcher.
+///
+/// The size is the length of the [Batch](crate::Batch) encoding that is written into a
+/// [ChannelOut](crate::ChannelOut), including the batch type byte.
+#[derive(Debug, Clone)]
+pub struct SpanBatchBuilder {
+    /// The span batch being built.
+    span: SpanBatch,
+    /// The target size of the encoded span batch.
+    target_size: usize,
+    /// The running totals of the span batch.
+    size: SpanBatchSize,
+}
+
+impl SpanBatchBuilder {
+    /// Creates a new [SpanBatchBuilder] for the given chain, targeting an encoded span batch of at
+    /// most `target_size` bytes.
+    pub fn new(genesis_timestamp: u64, chain_id: u64, target_size: usize) -> Self {
+        Self {
+            span: SpanBatch { genesis_timestamp, chain_id, ..Default::default() },
+            target_size,
+            size: SpanBatchSize::default(),
+        }
+    }
+
+    /// Returns the number of blocks in the span batch.
+    pub fn len(&self) -> usize {
+        self.span.batches.len()
+    }
+
+    /// Returns if the span batch holds no blocks.
+    pub fn is_empty(&self) -> bool {
+        self.span.batches.is_empty()
+    }
+
+    /// Returns the target size of the encoded span batch.
+    pub const fn target_size(&self) -> usize {
+        self.target_size
+    }
+
+    /// Returns the [SpanBatch] built so far.
+    pub const fn span_batch(&self) -> &SpanBatch {
+        &self.span
+    }
+
+    /// Returns the encoded size of the span batch built so far, or `0` if it is empty.
+    pub fn estimated_size(&self) -> usize {
+        match (self.span.batches.first(), self.span.batches.last()) {
+            (Some(first), Some(last)) => self.size.encoded_len(
+                first.timestamp.saturating_sub(self.span.genesis_timestamp),
+                last.epoch_num,
+            ),
+            _ => 0,
+        }
+    }
+
+    /// Returns the encoded size the span batch would have after adding the given batch.
+    ///
+    /// Returns an error if the batch is out of order, or holds transactions that cannot be
+    /// encoded into a span batch.
+    pub fn size_with(&self, batch: &SingleBatch) -> Result<usize, SpanBatchBuilderError> {
+        self.next_size(batch).map(|(_, size)| size)
+    }
+
+    /// Checks that the batch can be appended, and returns the running totals and encoded size of
+    /// the span batch with the batch added.
+    fn next_size(
+        &self,
+        batch: &SingleBatch,
+    ) -> Result<(SpanBatchSize, usize), SpanBatchBuilderError> {
+        if let Some(last) = self.span.batches.last() {
+            if batch.timestamp <= last.timestamp {
+                return Err(SpanBatchBuilderError::NotOrdered {
+                    last: last.timestamp,
+                    next: batch.timestamp,
+                });
+            }
+            if batch.epoch_num < last.epoch_num {
+                return Err(SpanBatchBuilderError::EpochDecreased {
+                    last: last.epoch_num,
+                    next: batch.epoch_num,
+                });
+            }
+        }
+
+        // Encode the transactions on their own, to validate them and measure their size.
+        let mut txs = SpanBatchTransactions::default();
+        txs.add_txs(batch.transactions.clone(), self.span.chain_id)?;
+
+        let size = self.size.with_block(&txs);
+        if size.blocks as u64 > MAX_SPAN_BATCH_ELEMENTS || size.txs as u64 > MAX_SPAN_BATCH_ELEMENTS
+        {
+            return Err(SpanBatchError::TooBigSpanBatchSize.into());
+        }
+
+        let first_timestamp = self.span.batches.first().map_or(batch.timestamp, |b| b.timestamp);
+        let encoded_len = size.encoded_len(
+            first_timestamp.saturating_sub(self.span.genesis_timestamp),
+            batch.epoch_num,
+        );
+        Ok((size, encoded_len))
+    }
+
+    /// Adds a [SingleBatch] to the span batch, returning the new encoded size of the span batch.
+    ///
+    /// The `seq_num` is the sequence number of the first batch within its epoch, and is ignored
+    /// for all later batches. If an error is returned, the builder is left unchanged.
+    pub fn add_batch(
+        &mut self,
+        batch: SingleBatch,
+        seq_num: u64,
+    ) -> Result<usize, SpanBatchBuilderError> {
+        let (size, encoded_len) = self.next_size(&batch)?;
+        if !self.is_empty() && encoded_len > self.target_size {
+            return Err(SpanBatchBuilderError::TargetSizeExceeded {
+                size: encoded_len,
+                target: self.target_size,
+            });
+        }
+
+        self.span.append_singular_batch(batch, seq_num)?;
+        self.size = size;
+        Ok(encoded_len)
+    }
+
+    /// Finishes the span batch, returning the [RawSpanBatch] to encode into a
+    /// [ChannelOut](crate::ChannelOut).
+    ///
+    /// Returns [SpanBatchError::EmptySpanBatch] if no batches were added.
+    pub fn finish(self) -> Result<RawSpanBatch, SpanBatchBuilderError> {
+        Ok(self.span.to_raw_span_batch()?)
+    }
+
+    /// Finishes the span batch, returning the [SpanBatch] to add to a
+    /// [ChannelOut](crate::ChannelOut) as a [Batch::Span](crate::Batch::Span).
+    pub fn into_span_batch(self) -> SpanBatch {
+        self.span
+    }
+
+    /// Resets the builder to start a new span batch, keeping its chain parameters and target size.
+    pub fn reset(&mut self) {
+        *self = Self::new(self.span.genesis_timestamp, self.span.chain_id, self.target_size);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Batch;
+    use alloc::{vec, vec::Vec};
+    use alloy_consensus::{SignableTransaction, TxEip1559, TxEnvelope, TxLegacy};
+    use alloy_eips::eip2718::Encodable2718;
+    use alloy_primitives::{
+        Address, Bytes, FixedBytes, PrimitiveSignature as Signature, TxKind, U256,
+    };
+
+    const CHAIN_ID: u64 = 10;
+
+    fn eip1559_tx(nonce: u64, to: TxKind) -> Bytes {
+        let tx = TxEip1559 {
+            chain_id: CHAIN_ID,
+            nonce,
+            gas_limit: 21_000,
+            to,
+            value: U256::from(7_u64),
+            input: vec![0xAA; 40].into(),
+            ..Default::default()
+        };
+        TxEnvelope::from(tx.into_signed(Signature::test_signature())).encoded_2718().into()
+    }
+
+    fn legacy_tx(nonce: u64) -> Bytes {
+        let tx = TxLegacy {
+            chain_id: Some(CHAIN_ID),
+            nonce,
+            gas_limit: 100_000,
+            to: TxKind::Call(Address::left_padding_from(&[7])),
+            ..Default::default()
+        };
+        TxEnvelope::from(tx.into_signed(Signature::test_signature())).encoded_2718().into()
+    }
+
+    fn single_batch(epoch_num: u64, timestamp: u64, transactions: Vec<Bytes>) -> SingleBatch {
+        SingleBatch {
+            parent_hash: FixedBytes::from([0x11; 32]),
+            epoch_num,
+            epoch_hash: FixedBytes::from([epoch_num as u8; 32]),
+            timestamp,
+            transactions,
+        }
+    }
+
+    fn encoded_len(builder: &SpanBatchBuilder) -> usize {
+        let mut buf = Vec::new();
+        Batch::Span(builder.span_batch().clone()).encode(&mut buf).unwrap();
+        buf.len()
+    }
+
+    #[test]
+    fn test_estimated_size_matches_encoding() {
+        let mut builder = SpanBatchBuilder::new(1_000, CHAIN_ID, usize::MAX);
+        assert_eq!(builder.estimated_size(), 0);
+
+        let batches = [
+            single_batch(1, 1_002, vec![]),
+            single_batch(1, 1_004, vec![eip1559_tx(0, TxKind::Create)]),
+            single_batch(2, 1_006, vec![eip1559_tx(1, Address::ZERO.into()), legacy_tx(300)]),
+            single_batch(2, 1_008, (0..9).map(|n| legacy_tx(n + 400)).collect()),
+            single_batch(300, 1_010, vec![eip1559_tx(u64::MAX, TxKind::Create)]),
+        ];
+        for (i, batch) in batches.into_iter().enumerate() {
+            let expected = builder.size_with(&batch).unwrap();
+            assert_eq!(builder.add_batch(batch, i as u64).unwrap(), expected);
+            assert_eq!(builder.estimated_size(), expected);
+            assert_eq!(encoded_len(&builder), expected);
+        }
+        assert_eq!(builder.len(), 5);
+    }
+
+    #[test]
+    fn test_add_batch_not_ordered() {
+        let mut builder = SpanBatchBuilder::new(0, CHAIN_ID, usize::MAX);
+        builder.add_batch(single_batch(1, 10, vec![]), 0).unwrap();
+        let size = builder.estimated_size();
+
+        for timestamp in [10, 8] {
+            assert_eq!(
+                builder.add_batch(single_batch(1, timestamp, vec![]), 1),
+                Err(SpanBatchBuilderError::NotOrdered { last: 10, next: timestamp })
+            );
+        }
+        assert_eq!(builder.len(), 1);
+        assert_eq!(builder.estimated_size(), size);
+    }
+
+    #[test]
+    fn test_add_batch_epoch_decreased() {
+        let mut builder = SpanBatchBuilder::new(0, CHAIN_ID, usize::MAX);
+        builder.add_batch(single_batch(5, 10, vec![]), 0).unwrap();
+        assert_eq!(
+            builder.add_batch(single_batch(4, 12, vec![]), 1),
+            Err(SpanBatchBuilderError::EpochDecreased { last: 5, next: 4 })
+        );
+        assert_eq!(builder.len(), 1);
+    }
+
+    #[test]
+    fn test_add_batch_invalid_transaction() {
+        let mut builder = SpanBatchBuilder::new(0, CHAIN_ID, usize::MAX);
+        builder.add_batch(single_batch(1, 10, vec![legacy_tx(0)]), 0).unwrap();
+        let size = builder.estimated_size();
+
+        let batch = single_batch(1, 12, vec![legacy_tx(1), Bytes::from_static(&[0xFF])]);
+        assert!(matches!(builder.add_batch(batch, 1), Err(SpanBatchBuilderError::SpanBatch(_))));
+        assert_eq!(builder.len(), 1);
+        assert_eq!(builder.span_batch().txs.total_block_tx_count, 1);
+        assert_eq!(builder.estimated_size(), size);
+        assert_eq!(encoded_len(&builder), size);
+    }
+
+    #[test]
+    fn test_add_batch_target_size_exceeded() {
+        let first = single_batch(1, 10, vec![legacy_tx(0), legacy_tx(1)]);
+        let target = SpanBatchBuilder::new(0, CHAIN_ID, 0).size_with(&first).unwrap() + 10;
+
+        let mut builder = SpanBatchBuilder::new(0, CHAIN_ID, target);
+        builder.add_batch(first, 0).unwrap();
+        // An empty block still fits.
+        builder.add_batch(single_batch(1, 12, vec![]), 1).unwrap();
+
+        let next = single_batch(1, 14, vec![legacy_tx(2)]);
+        let size = builder.size_with(&next).unwrap();
+        assert_eq!(
+            builder.add_batch(next, 2),
+            Err(SpanBatchBuilderError::TargetSizeExceeded { size, target })
+        );
+        assert_eq!(builder.len(), 2);
+        assert!(builder.estimated_size() <= target);
+    }
+
+    #[test]
+    fn test_add_batch_first_batch_exceeds_target() {
+        let mut builder = SpanBatchBuilder::new(0, CHAIN_ID, 1);
+        let size = builder.add_batch(single_batch(1, 10, vec![legacy_tx(0)]), 0).unwrap();
+        assert!(size > builder.target_size());
+        assert_eq!(builder.len(), 1);
+    }
+
+    #[test]
+    fn test_finish() {
+        let builder = SpanBatchBuilder::new(0, CHAIN_ID, usize::MAX);
+        assert_eq!(
+            builder.finish(),
+            Err(SpanBatchBuilderError::SpanBatch(SpanBatchError::EmptySpanBatch))
+        );
+
+        let mut builder = SpanBatchBuilder::new(100, CHAIN_ID, usize::MAX);
+        builder.add_batch(single_batch(1, 102, vec![eip1559_tx(0, TxKind::Create)]), 0).unwrap();
+        builder.add_batch(single_batch(2, 104, vec![legacy_tx(1)]), 0).unwrap();
+        let size = builder.estimated_size();
+        let raw = builder.finish().unwrap();
+
+        assert_eq!(raw.prefix.rel_timestamp, 2);
+        assert_eq!(raw.prefix.l1_origin_num, 2);
+        assert_eq!(raw.prefix.l1_origin_check, FixedBytes::from([2; 20]));
+        assert_eq!(raw.payload.block_count, 2);
+
+        let mut buf = Vec::new();
+        raw.encode(&mut buf).unwrap();
+        assert_eq!(buf.len() + 1, size);
+        assert_eq!(RawSpanBatch::decode(&mut buf.as_slice()).unwrap(), raw);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut builder = SpanBatchBuilder::new(100, CHAIN_ID, 1_000);
+        builder.add_batch(single_batch(1, 102, vec![legacy_tx(0)]), 0).unwrap();
+        builder.reset();
+        assert!(builder.is_empty());
+        assert_eq!(builder.estimated_size(), 0);
+        assert_eq!(builder.target_size(), 1_000);
+        assert_eq!(builder.span_batch().genesis_timestamp, 100);
+        assert_eq!(builder.span_batch().chain_id, CHAIN_ID);
+    }
+}
@@ -15,11 +15,46 @@ pub enum SpanBatchError {
     /// Missing L1 origin
     #[error("Missing L1 origin")]
     MissingL1Origin,
+    /// A batch was appended out of timestamp order
+    #[error("Batch is not ordered")]
+    BatchNotOrdered,
     /// Decoding errors
     #[error("Span batch decoding error: {0}")]
     Decoding(#[from] SpanDecodingError),
 }
 
+/// An error returned by the [SpanBatchBuilder](crate::SpanBatchBuilder).
+#[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
+pub enum SpanBatchBuilderError {
+    /// The batch timestamp is not after the timestamp of the last batch in the span.
+    #[error("Batch timestamp {next} is not after the last batch timestamp {last}")]
+    NotOrdered {
+        /// The timestamp of the last batch in the span.
+        last: u64,
+        /// The timestamp of the rejected batch.
+        next: u64,
+    },
+    /// The batch epoch is before the epoch of the last batch in the span.
+    #[error("Batch epoch {next} is before the last batch epoch {last}")]
+    EpochDecreased {
+        /// The epoch number of the last batch in the span.
+        last: u64,
+        /// The epoch number of the rejected batch.
+        next: u64,
+    },
+    /// Adding the batch would grow the span batch past the target size.
+    #[error("Span batch size {size} would exceed the target size {target}")]
+    TargetSizeExceeded {
+        /// The estimated size of the span batch with the rejected batch.
+        size: usize,
+        /// The target size.
+        target: usize,
+    },
+    /// A span batch error.
+    #[error("Span batch error: {0}")]
+    SpanBatch(#[from] SpanBatchError),
+}
+
 /// An error encoding a batch.
 #[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
 pub enum BatchEncodingError {
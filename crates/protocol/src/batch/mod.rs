@@ -4,7 +4,7 @@ mod r#type;
 pub use r#type::*;
 
 mod reader;
-pub use reader::BatchReader;
+pub use reader::{BatchReader, BatchReaderError, ChannelCompression};
 
 mod tx;
 pub use tx::BatchTransaction;
@@ -25,7 +25,10 @@ mod inclusion;
 pub use inclusion::BatchWithInclusionBlock;
 
 mod errors;
-pub use errors::{BatchDecodingError, BatchEncodingError, SpanBatchError, SpanDecodingError};
+pub use errors::{
+    BatchDecodingError, BatchEncodingError, SpanBatchBuilderError, SpanBatchError,
+    SpanDecodingError,
+};
 
 mod bits;
 pub use bits::SpanBatchBits;
@@ -33,6 +36,9 @@ pub use bits::SpanBatchBits;
 mod span;
 pub use span::SpanBatch;
 
+mod builder;
+pub use builder::SpanBatchBuilder;
+
 mod transactions;
 pub use transactions::SpanBatchTransactions;
 
@@ -1,11 +1,12 @@
 //! Contains the [BatchReader] which is used to iteratively consume batches from raw data.
 
-use crate::{decompress_brotli, Batch};
+use crate::{decompress_brotli, Batch, BatchDecodingError, BrotliDecompressionError};
 use alloc::vec::Vec;
 use alloy_primitives::Bytes;
 use alloy_rlp::Decodable;
 use maili_genesis::RollupConfig;
-use miniz_oxide::inflate::decompress_to_vec_zlib;
+use miniz_oxide::inflate::{decompress_to_vec_zlib_with_limit, TINFLStatus};
+
 /// ZLIB Deflate Compression Method.
 const ZLIB_DEFLATE_COMPRESSION_METHOD: u8 = 8;
 
@@ -15,6 +16,47 @@ const ZLIB_RESERVED_COMPRESSION_METHOD: u8 = 15;
 /// Brotili Compression Channel Version.
 const CHANNEL_VERSION_BROTLI: u8 = 1;
 
+/// The compression algorithm of a channel, detected from its first byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChannelCompression {
+    /// Zlib compression.
+    Zlib,
+    /// Brotli compression, only valid after the Fjord hardfork.
+    Brotli,
+}
+
+impl core::fmt::Display for ChannelCompression {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Zlib => write!(f, "zlib"),
+            Self::Brotli => write!(f, "brotli"),
+        }
+    }
+}
+
+/// An error returned by the [BatchReader].
+#[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
+pub enum BatchReaderError {
+    /// All batches in the channel have been read.
+    #[error("Channel exhausted")]
+    Exhausted,
+    /// The first byte of the channel data does not match a known compression algorithm.
+    #[error("Unsupported channel compression type: {0}")]
+    UnsupportedCompression(u8),
+    /// The channel data failed to decompress.
+    #[error("Failed to decompress {0} channel data")]
+    Decompression(ChannelCompression),
+    /// The decompressed channel data exceeds the maximum RLP bytes per channel.
+    #[error("Decompressed channel data exceeds the maximum of {0} RLP bytes")]
+    SizeLimit(usize),
+    /// The batch failed to decode.
+    #[error("Failed to decode batch: {0}")]
+    Decode(#[from] BatchDecodingError),
+    /// Brotli compressed batches are only valid after the Fjord hardfork.
+    #[error("Brotli compressed batch with timestamp {0} before Fjord")]
+    BrotliBeforeFjord(u64),
+}
+
 /// Batch Reader provides a function that iteratively consumes batches from the reader.
 /// The L1Inclusion block is also provided at creation time.
 /// Warning: the batch reader can read every batch-type.
@@ -29,6 +71,8 @@ pub struct BatchReader {
     cursor: usize,
     /// The maximum RLP bytes per channel.
     max_rlp_bytes_per_channel: usize,
+    /// The compression algorithm detected from the channel data.
+    compression: Option<ChannelCompression>,
 }
 
 impl BatchReader {
@@ -42,54 +86,108 @@ impl BatchReader {
             decompressed: Vec::new(),
             cursor: 0,
             max_rlp_bytes_per_channel,
+            compression: None,
         }
     }
 
+    /// Returns the compression algorithm detected from the channel data, if the data has been
+    /// read.
+    pub const fn compression(&self) -> Option<ChannelCompression> {
+        self.compression
+    }
+
+    /// Returns the number of decompressed bytes consumed by the batches read so far.
+    pub const fn consumed(&self) -> usize {
+        self.cursor
+    }
+
+    /// Returns the length of the decompressed channel data, or `0` if the data has not been
+    /// decompressed yet.
+    pub fn decompressed_len(&self) -> usize {
+        self.decompressed.len()
+    }
+
+    /// Decompresses the channel data, detecting the compression algorithm from its first byte.
+    fn decompress(&mut self, data: &[u8]) -> Result<(), BatchReaderError> {
+        let Some(&compression_type) = data.first() else {
+            return Err(BatchReaderError::Exhausted);
+        };
+
+        if (compression_type & 0x0F) == ZLIB_DEFLATE_COMPRESSION_METHOD
+            || (compression_type & 0x0F) == ZLIB_RESERVED_COMPRESSION_METHOD
+        {
+            self.compression = Some(ChannelCompression::Zlib);
+            // Decompression stops as soon as the channel RLP exceeds its maximum size.
+            self.decompressed =
+                decompress_to_vec_zlib_with_limit(data, self.max_rlp_bytes_per_channel).map_err(
+                    |e| match e.status {
+                        TINFLStatus::HasMoreOutput => {
+                            BatchReaderError::SizeLimit(self.max_rlp_bytes_per_channel)
+                        }
+                        _ => BatchReaderError::Decompression(ChannelCompression::Zlib),
+                    },
+                )?;
+        } else if compression_type == CHANNEL_VERSION_BROTLI {
+            self.compression = Some(ChannelCompression::Brotli);
+            self.decompressed = decompress_brotli(&data[1..], self.max_rlp_bytes_per_channel)
+                .map_err(|e| match e {
+                    BrotliDecompressionError::BatchTooLarge => {
+                        BatchReaderError::SizeLimit(self.max_rlp_bytes_per_channel)
+                    }
+                    BrotliDecompressionError::InvalidStream => {
+                        BatchReaderError::Decompression(ChannelCompression::Brotli)
+                    }
+                })?;
+        } else {
+            return Err(BatchReaderError::UnsupportedCompression(compression_type));
+        }
+        Ok(())
+    }
+
     /// Pulls out the next batch from the reader.
-    pub fn next_batch(&mut self, cfg: &RollupConfig) -> Option<Batch> {
-        // If the data is not already decompressed, decompress it.
-        let mut brotli_used = false;
+    ///
+    /// The channel data is decompressed on the first call. Once the data is exhausted, or if it
+    /// fails to decompress, or a batch fails to decode or is rejected, the remaining data is
+    /// dropped and [BatchReaderError::Exhausted] is returned by all further calls.
+    pub fn next_batch(&mut self, cfg: &RollupConfig) -> Result<Batch, BatchReaderError> {
+        let batch = self.read_batch(cfg);
+        if matches!(
+            batch,
+            Err(BatchReaderError::Decode(_) | BatchReaderError::BrotliBeforeFjord(_))
+        ) {
+            self.decompressed.clear();
+            self.cursor = 0;
+        }
+        batch
+    }
 
+    /// Reads the next batch at the cursor, advancing the cursor if it is valid.
+    fn read_batch(&mut self, cfg: &RollupConfig) -> Result<Batch, BatchReaderError> {
+        // If the data is not already decompressed, decompress it.
         if let Some(data) = self.data.take() {
-            // Peek at the data to determine the compression type.
-            if data.is_empty() {
-                return None;
-            }
-
-            let compression_type = data[0];
-            if (compression_type & 0x0F) == ZLIB_DEFLATE_COMPRESSION_METHOD
-                || (compression_type & 0x0F) == ZLIB_RESERVED_COMPRESSION_METHOD
-            {
-                self.decompressed = decompress_to_vec_zlib(&data).ok()?;
-
-                // Check the size of the decompressed channel RLP.
-                if self.decompressed.len() > self.max_rlp_bytes_per_channel {
-                    return None;
-                }
-            } else if compression_type == CHANNEL_VERSION_BROTLI {
-                brotli_used = true;
-                self.decompressed =
-                    decompress_brotli(&data[1..], self.max_rlp_bytes_per_channel).ok()?;
-            } else {
-                return None;
-            }
+            self.decompress(&data)?;
+        }
+
+        if self.cursor >= self.decompressed.len() {
+            return Err(BatchReaderError::Exhausted);
         }
 
-        // Decompress and RLP decode the batch data, before finally decoding the batch itself.
+        // RLP decode the batch data, before finally decoding the batch itself.
         let decompressed_reader = &mut self.decompressed.as_slice()[self.cursor..].as_ref();
-        let bytes = Bytes::decode(decompressed_reader).ok()?;
-        let Ok(batch) = Batch::decode(&mut bytes.as_ref(), cfg) else {
-            return None;
-        };
+        let bytes =
+            Bytes::decode(decompressed_reader).map_err(BatchDecodingError::AlloyRlpError)?;
+        let batch = Batch::decode(&mut bytes.as_ref(), cfg)?;
 
         // Confirm that brotli decompression was performed *after* the Fjord hardfork.
-        if brotli_used && !cfg.is_fjord_active(batch.timestamp()) {
-            return None;
+        if self.compression == Some(ChannelCompression::Brotli)
+            && !cfg.is_fjord_active(batch.timestamp())
+        {
+            return Err(BatchReaderError::BrotliBeforeFjord(batch.timestamp()));
         }
 
         // Advance the cursor on the reader.
         self.cursor = self.decompressed.len() - decompressed_reader.len();
-        Some(batch)
+        Ok(batch)
     }
 }
 
@@ -97,6 +195,7 @@ impl BatchReader {
 mod test {
     use super::*;
     use maili_genesis::{MAX_RLP_BYTES_PER_CHANNEL_BEDROCK, MAX_RLP_BYTES_PER_CHANNEL_FJORD};
+    use miniz_oxide::inflate::decompress_to_vec_zlib;
 
     fn new_compressed_batch_data() -> Bytes {
         let file_contents =
@@ -106,13 +205,22 @@ mod test {
         data.into()
     }
 
+    fn new_brotli_batch_data() -> Vec<u8> {
+        let file_contents = alloc::string::String::from_utf8_lossy(include_bytes!(
+            "../../testdata/batch_brotli.hex"
+        ));
+        alloy_primitives::hex::decode(file_contents.trim_end()).unwrap()
+    }
+
     #[test]
     fn test_batch_reader() {
         let raw = new_compressed_batch_data();
         let decompressed_len = decompress_to_vec_zlib(&raw).unwrap().len();
         let mut reader = BatchReader::new(raw, MAX_RLP_BYTES_PER_CHANNEL_BEDROCK as usize);
         reader.next_batch(&RollupConfig::default()).unwrap();
-        assert_eq!(reader.cursor, decompressed_len);
+        assert_eq!(reader.consumed(), decompressed_len);
+        assert_eq!(reader.decompressed_len(), decompressed_len);
+        assert_eq!(reader.compression(), Some(ChannelCompression::Zlib));
     }
 
     #[test]
@@ -121,6 +229,106 @@ mod test {
         let decompressed_len = decompress_to_vec_zlib(&raw).unwrap().len();
         let mut reader = BatchReader::new(raw, MAX_RLP_BYTES_PER_CHANNEL_FJORD as usize);
         reader.next_batch(&RollupConfig { fjord_time: Some(0), ..Default::default() }).unwrap();
-        assert_eq!(reader.cursor, decompressed_len);
+        assert_eq!(reader.consumed(), decompressed_len);
+    }
+
+    #[test]
+    fn test_batch_reader_exhausted() {
+        let cfg = RollupConfig::default();
+        let mut reader =
+            BatchReader::new(new_compressed_batch_data(), MAX_RLP_BYTES_PER_CHANNEL_BEDROCK as _);
+        assert!(reader.next_batch(&cfg).is_ok());
+        assert_eq!(reader.next_batch(&cfg), Err(BatchReaderError::Exhausted));
+
+        let mut reader = BatchReader::new(Vec::new(), MAX_RLP_BYTES_PER_CHANNEL_BEDROCK as _);
+        assert_eq!(reader.next_batch(&cfg), Err(BatchReaderError::Exhausted));
+        assert_eq!(reader.compression(), None);
+    }
+
+    #[test]
+    fn test_batch_reader_unsupported_compression() {
+        let mut reader = BatchReader::new([0x02, 0x00], MAX_RLP_BYTES_PER_CHANNEL_BEDROCK as _);
+        assert_eq!(
+            reader.next_batch(&RollupConfig::default()),
+            Err(BatchReaderError::UnsupportedCompression(0x02))
+        );
+        assert_eq!(reader.next_batch(&RollupConfig::default()), Err(BatchReaderError::Exhausted));
+    }
+
+    #[test]
+    fn test_batch_reader_zlib_decompression_failure() {
+        let mut raw = new_compressed_batch_data().to_vec();
+        raw.truncate(raw.len() / 2);
+        let mut reader = BatchReader::new(raw, MAX_RLP_BYTES_PER_CHANNEL_BEDROCK as _);
+        assert_eq!(
+            reader.next_batch(&RollupConfig::default()),
+            Err(BatchReaderError::Decompression(ChannelCompression::Zlib))
+        );
+        assert_eq!(reader.compression(), Some(ChannelCompression::Zlib));
+    }
+
+    #[test]
+    fn test_batch_reader_zlib_size_limit() {
+        let mut reader = BatchReader::new(new_compressed_batch_data(), 100);
+        assert_eq!(
+            reader.next_batch(&RollupConfig::default()),
+            Err(BatchReaderError::SizeLimit(100))
+        );
+        assert_eq!(reader.decompressed_len(), 0);
+        assert_eq!(reader.next_batch(&RollupConfig::default()), Err(BatchReaderError::Exhausted));
+    }
+
+    #[test]
+    fn test_batch_reader_decode_error() {
+        let data = miniz_oxide::deflate::compress_to_vec_zlib(&[0x83, 0x00, 0x01, 0x02], 6);
+        let mut reader = BatchReader::new(data, MAX_RLP_BYTES_PER_CHANNEL_BEDROCK as _);
+        assert!(matches!(
+            reader.next_batch(&RollupConfig::default()),
+            Err(BatchReaderError::Decode(_))
+        ));
+        // The remaining data is dropped.
+        assert_eq!(reader.decompressed_len(), 0);
+        assert_eq!(reader.next_batch(&RollupConfig::default()), Err(BatchReaderError::Exhausted));
+    }
+
+    #[test]
+    fn test_batch_reader_brotli() {
+        let data = new_brotli_batch_data();
+        let mut reader = BatchReader::new(data, MAX_RLP_BYTES_PER_CHANNEL_FJORD as _);
+        let cfg = RollupConfig { fjord_time: Some(0), ..Default::default() };
+        assert!(reader.next_batch(&cfg).is_ok());
+        assert_eq!(reader.compression(), Some(ChannelCompression::Brotli));
+        assert_eq!(reader.consumed(), reader.decompressed_len());
+    }
+
+    #[test]
+    fn test_batch_reader_brotli_before_fjord() {
+        let data = new_brotli_batch_data();
+        let mut reader = BatchReader::new(data, MAX_RLP_BYTES_PER_CHANNEL_FJORD as _);
+        let cfg = RollupConfig::default();
+        assert!(matches!(reader.next_batch(&cfg), Err(BatchReaderError::BrotliBeforeFjord(_))));
+        // The remaining data is dropped.
+        assert_eq!(reader.decompressed_len(), 0);
+        assert_eq!(reader.next_batch(&cfg), Err(BatchReaderError::Exhausted));
+    }
+
+    #[test]
+    fn test_batch_reader_brotli_size_limit() {
+        let mut reader = BatchReader::new(new_brotli_batch_data(), 100);
+        let cfg = RollupConfig { fjord_time: Some(0), ..Default::default() };
+        assert_eq!(reader.next_batch(&cfg), Err(BatchReaderError::SizeLimit(100)));
+    }
+
+    #[test]
+    fn test_batch_reader_brotli_decompression_failure() {
+        let mut data = new_brotli_batch_data();
+        data.truncate(data.len() / 2);
+        let mut reader = BatchReader::new(data, MAX_RLP_BYTES_PER_CHANNEL_FJORD as _);
+        let cfg = RollupConfig { fjord_time: Some(0), ..Default::default() };
+        assert_eq!(
+            reader.next_batch(&cfg),
+            Err(BatchReaderError::Decompression(ChannelCompression::Brotli))
+        );
+        assert_eq!(reader.compression(), Some(ChannelCompression::Brotli));
     }
 }
@@ -141,9 +141,9 @@ impl SpanBatch {
         singular_batch: SingleBatch,
         seq_num: u64,
     ) -> Result<(), SpanBatchError> {
-        // If the new element is not ordered with respect to the last element, panic.
+        // If the new element is not ordered with respect to the last element, error.
         if !self.batches.is_empty() && self.peek(0).timestamp > singular_batch.timestamp {
-            panic!("Batch is not ordered");
+            return Err(SpanBatchError::BatchNotOrdered);
         }
 
         let SingleBatch { epoch_hash, parent_hash, .. } = singular_batch;
@@ -583,6 +583,20 @@ mod tests {
         assert!(batch.append_singular_batch(singular_batch, 1).is_ok());
     }
 
+    #[test]
+    fn test_append_unordered_singular_batch() {
+        let mut batch = SpanBatch::default();
+        let singular_batch = SingleBatch { timestamp: 20, ..Default::default() };
+        assert!(batch.append_singular_batch(singular_batch, 0).is_ok());
+
+        let singular_batch = SingleBatch { timestamp: 10, ..Default::default() };
+        assert_eq!(
+            batch.append_singular_batch(singular_batch, 1),
+            Err(SpanBatchError::BatchNotOrdered)
+        );
+        assert_eq!(batch.batches.len(), 1);
+    }
+
     #[test]
     fn test_check_origin_hash() {
         let l1_origin_check = FixedBytes::from([17u8; 20]);
@@ -0,0 +1,340 @@
+//! Blob Types
+//!
+//! See: <https://specs.optimism.io/protocol/derivation.html#blob-encoding>
+
+use alloc::vec;
+use alloy_eips::eip4844::{Blob, BYTES_PER_BLOB};
+use alloy_primitives::Bytes;
+
+/// The version of the blob encoding.
+pub const BLOB_ENCODING_VERSION: u8 = 0;
+
+/// The offset of the encoding version byte in the blob.
+const VERSION_OFFSET: usize = 1;
+
+/// The number of encoding rounds. Each round packs 4 field elements.
+const ENCODING_ROUNDS: usize = 1024;
+
+/// The maximum amount of data that can be encoded into a single blob.
+///
+/// Each round packs 127 bytes into 4 field elements, minus the version byte and the 3-byte length
+/// header of the first round.
+pub const BLOB_MAX_DATA_SIZE: usize = (4 * 31 + 3) * ENCODING_ROUNDS - 4;
+
+/// An error encountered while encoding data into a [Blob].
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlobEncodingError {
+    /// The data is too large to fit into a single blob.
+    #[error("Data too large to fit into a blob: {0} bytes")]
+    DataTooLarge(usize),
+}
+
+/// An error encountered while decoding data from a [Blob].
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlobDecodingError {
+    /// The blob encoding version is not supported.
+    #[error("Invalid blob encoding version: {0}")]
+    InvalidVersion(u8),
+    /// The encoded data length exceeds the max blob data size.
+    #[error("Invalid blob data length: {0}")]
+    InvalidLength(u32),
+    /// A field element is not canonical, its two highest order bits are set.
+    #[error("Invalid field element at blob offset {0}")]
+    InvalidFieldElement(usize),
+    /// The blob holds non-zero data past the encoded length.
+    #[error("Extraneous data at blob offset {0}")]
+    ExtraneousData(usize),
+}
+
+/// The OP Stack blob encoding of batcher data.
+///
+/// Data is packed into the blob in 1024 rounds of 4 field elements. Each field element holds 31
+/// bytes of data after its first byte, and the first bytes of the 4 field elements hold 6 bits
+/// each, which together carry 3 more bytes of data. The first round starts with the encoding
+/// version and the big-endian 3-byte length of the data.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BlobData;
+
+impl BlobData {
+    /// Encodes the given data into a [Blob].
+    pub fn encode(data: &[u8]) -> Result<Blob, BlobEncodingError> {
+        if data.len() > BLOB_MAX_DATA_SIZE {
+            return Err(BlobEncodingError::DataTooLarge(data.len()));
+        }
+
+        let mut blob = Blob::ZERO;
+        let mut reader = Reader { data, offset: 0 };
+        let mut write_offset = 0;
+        let mut buf31 = [0u8; 31];
+
+        // Writes a 6-bit value and 31 bytes as a single field element.
+        let mut write_field_element = |blob: &mut Blob, first: u8, buf31: &[u8; 31]| {
+            blob[write_offset] = first;
+            blob[write_offset + 1..write_offset + 32].copy_from_slice(buf31);
+            write_offset += 32;
+        };
+
+        for round in 0..ENCODING_ROUNDS {
+            if round == 0 {
+                buf31[0] = BLOB_ENCODING_VERSION;
+                buf31[1..4].copy_from_slice(&(data.len() as u32).to_be_bytes()[1..]);
+                buf31[4..].fill(0);
+                reader.read_into(&mut buf31[4..]);
+            } else {
+                reader.read31(&mut buf31);
+            }
+            let x = reader.read1();
+            write_field_element(&mut blob, x & 0b0011_1111, &buf31);
+
+            reader.read31(&mut buf31);
+            let y = reader.read1();
+            write_field_element(&mut blob, (y & 0b0000_1111) | ((x & 0b1100_0000) >> 2), &buf31);
+
+            reader.read31(&mut buf31);
+            let z = reader.read1();
+            write_field_element(&mut blob, z & 0b0011_1111, &buf31);
+
+            reader.read31(&mut buf31);
+            write_field_element(
+                &mut blob,
+                ((z & 0b1100_0000) >> 2) | ((y & 0b1111_0000) >> 4),
+                &buf31,
+            );
+        }
+
+        Ok(blob)
+    }
+
+    /// Decodes the data from the given [Blob].
+    pub fn decode(blob: &Blob) -> Result<Bytes, BlobDecodingError> {
+        let version = blob[VERSION_OFFSET];
+        if version != BLOB_ENCODING_VERSION {
+            return Err(BlobDecodingError::InvalidVersion(version));
+        }
+
+        let length = u32::from_be_bytes([0, blob[2], blob[3], blob[4]]);
+        if length as usize > BLOB_MAX_DATA_SIZE {
+            return Err(BlobDecodingError::InvalidLength(length));
+        }
+        let length = length as usize;
+
+        // Round 0 copies the remaining 27 bytes of the first field element, since the version
+        // and length occupy its first 5 bytes.
+        let mut output = vec![0u8; BLOB_MAX_DATA_SIZE];
+        output[0..27].copy_from_slice(&blob[5..32]);
+
+        let mut opos = 28;
+        let mut ipos = 32;
+        let mut encoded = [blob[0], 0, 0, 0];
+        for byte in encoded.iter_mut().skip(1) {
+            *byte = decode_field_element(blob, &mut opos, &mut ipos, &mut output)?;
+        }
+        opos = reassemble_bytes(opos, &encoded, &mut output);
+
+        // Each remaining round decodes 4 field elements into 127 bytes.
+        for _ in 1..ENCODING_ROUNDS {
+            if opos >= length {
+                break;
+            }
+            for byte in encoded.iter_mut() {
+                *byte = decode_field_element(blob, &mut opos, &mut ipos, &mut output)?;
+            }
+            opos = reassemble_bytes(opos, &encoded, &mut output);
+        }
+
+        // All bytes past the encoded length must be zero.
+        if let Some(i) = output[length..].iter().position(|b| *b != 0) {
+            return Err(BlobDecodingError::ExtraneousData(blob_offset(length + i)));
+        }
+        if let Some(i) = blob[ipos..].iter().position(|b| *b != 0) {
+            return Err(BlobDecodingError::ExtraneousData(ipos + i));
+        }
+
+        output.truncate(length);
+        Ok(output.into())
+    }
+}
+
+/// Reads the input data for [BlobData::encode], padding with zeros once the data is exhausted.
+struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl Reader<'_> {
+    /// Reads a single byte, or zero if the data is exhausted.
+    fn read1(&mut self) -> u8 {
+        let Some(byte) = self.data.get(self.offset) else {
+            return 0;
+        };
+        self.offset += 1;
+        *byte
+    }
+
+    /// Reads as much data as fits into `buf`, leaving the rest of `buf` untouched.
+    fn read_into(&mut self, buf: &mut [u8]) {
+        let remaining = &self.data[self.offset.min(self.data.len())..];
+        let n = remaining.len().min(buf.len());
+        buf[..n].copy_from_slice(&remaining[..n]);
+        self.offset += n;
+    }
+
+    /// Reads up to 31 bytes into `buf`, zero-padding the rest.
+    fn read31(&mut self, buf: &mut [u8; 31]) {
+        buf.fill(0);
+        self.read_into(buf);
+    }
+}
+
+/// Returns the offset in the blob of the byte at the given offset in the data.
+const fn blob_offset(data_offset: usize) -> usize {
+    // Each round is a stream of 127 bytes, which starts with the version and length in round 0.
+    let stream_offset = data_offset + 4;
+    let (round, offset) = (stream_offset / 127, stream_offset % 127);
+    // Every 32nd byte of the stream is carried by the first byte of a field element.
+    let (field_element, offset) = (offset / 32, offset % 32);
+    let offset = if offset == 31 { 0 } else { offset + 1 };
+    round * 128 + field_element * 32 + offset
+}
+
+/// Decodes a field element at `ipos` into `output` at `opos`, returning its first byte.
+fn decode_field_element(
+    blob: &Blob,
+    opos: &mut usize,
+    ipos: &mut usize,
+    output: &mut [u8],
+) -> Result<u8, BlobDecodingError> {
+    // The two highest order bits of the first byte of each field element must be zero.
+    let first = blob[*ipos];
+    if first & 0b1100_0000 != 0 {
+        return Err(BlobDecodingError::InvalidFieldElement(*ipos));
+    }
+    output[*opos..*opos + 31].copy_from_slice(&blob[*ipos + 1..*ipos + 32]);
+    *opos += 32;
+    *ipos += 32;
+    Ok(first)
+}
+
+/// Reassembles the 3 bytes carried by the first bytes of 4 field elements into their output
+/// positions, returning the new output position.
+fn reassemble_bytes(opos: usize, encoded: &[u8; 4], output: &mut [u8]) -> usize {
+    // There is no 128th output byte in a round.
+    let opos = opos - 1;
+    let x = (encoded[0] & 0b0011_1111) | ((encoded[1] & 0b0011_0000) << 2);
+    let y = (encoded[1] & 0b0000_1111) | ((encoded[3] & 0b0000_1111) << 4);
+    let z = (encoded[2] & 0b0011_1111) | ((encoded[3] & 0b0011_0000) << 2);
+    output[opos - 32] = z;
+    output[opos - 32 * 2] = y;
+    output[opos - 32 * 3] = x;
+    opos
+}
+
+// The blob encoding relies on the blob holding exactly 4096 field elements.
+const _: () = assert!(ENCODING_ROUNDS * 4 * 32 == BYTES_PER_BLOB);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Frame, DERIVATION_VERSION_0};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_encode_layout() {
+        let blob = BlobData::encode(b"hello").unwrap();
+        assert_eq!(blob[0], 0);
+        assert_eq!(blob[VERSION_OFFSET], BLOB_ENCODING_VERSION);
+        assert_eq!(&blob[2..5], &[0, 0, 5]);
+        assert_eq!(&blob[5..10], b"hello");
+        assert!(blob[10..].iter().all(|b| *b == 0));
+    }
+
+    #[test]
+    fn test_blob_offset() {
+        assert_eq!(blob_offset(0), 5);
+        assert_eq!(blob_offset(26), 31);
+        assert_eq!(blob_offset(27), 0);
+        assert_eq!(blob_offset(28), 33);
+        assert_eq!(blob_offset(123), 129);
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        for len in [0, 1, 27, 28, 31, 59, 123, 124, 127, 128, 1000, 4096, BLOB_MAX_DATA_SIZE] {
+            let data = (0..len).map(|i| (i % 256) as u8 ^ 0xA5).collect::<Vec<_>>();
+            let blob = BlobData::encode(&data).unwrap();
+            assert_eq!(BlobData::decode(&blob).unwrap(), data, "roundtrip failed for {len} bytes");
+        }
+    }
+
+    #[test]
+    fn test_roundtrip_all_high_bits() {
+        let data = vec![0xFF; BLOB_MAX_DATA_SIZE];
+        let blob = BlobData::encode(&data).unwrap();
+        assert!(blob.chunks(32).all(|fe| fe[0] & 0b1100_0000 == 0));
+        assert_eq!(BlobData::decode(&blob).unwrap(), data);
+    }
+
+    #[test]
+    fn test_roundtrip_frames() {
+        let frame = Frame { id: [0xFF; 16], number: 0, data: vec![0xDD; 500], is_last: true };
+        let mut data = vec![DERIVATION_VERSION_0];
+        data.extend(frame.encode());
+
+        let blob = BlobData::encode(&data).unwrap();
+        let decoded = BlobData::decode(&blob).unwrap();
+        assert_eq!(Frame::parse_frames(&decoded).unwrap(), vec![frame]);
+    }
+
+    #[test]
+    fn test_encode_data_too_large() {
+        let data = vec![0; BLOB_MAX_DATA_SIZE + 1];
+        assert_eq!(
+            BlobData::encode(&data).unwrap_err(),
+            BlobEncodingError::DataTooLarge(BLOB_MAX_DATA_SIZE + 1)
+        );
+    }
+
+    #[test]
+    fn test_decode_invalid_version() {
+        let mut blob = BlobData::encode(b"hello").unwrap();
+        blob[VERSION_OFFSET] = 1;
+        assert_eq!(BlobData::decode(&blob).unwrap_err(), BlobDecodingError::InvalidVersion(1));
+    }
+
+    #[test]
+    fn test_decode_invalid_length() {
+        let mut blob = BlobData::encode(b"hello").unwrap();
+        let length = BLOB_MAX_DATA_SIZE as u32 + 1;
+        blob[2..5].copy_from_slice(&length.to_be_bytes()[1..]);
+        assert_eq!(BlobData::decode(&blob).unwrap_err(), BlobDecodingError::InvalidLength(length));
+    }
+
+    #[test]
+    fn test_decode_invalid_field_element() {
+        let mut blob = BlobData::encode(&[0xAB; 1000]).unwrap();
+        blob[32 * 5] |= 0b1000_0000;
+        assert_eq!(
+            BlobData::decode(&blob).unwrap_err(),
+            BlobDecodingError::InvalidFieldElement(32 * 5)
+        );
+    }
+
+    #[test]
+    fn test_decode_extraneous_data() {
+        // Non-zero data within the decoded rounds, past the encoded length.
+        let mut blob = BlobData::encode(b"hello").unwrap();
+        blob[10] = 1;
+        assert_eq!(BlobData::decode(&blob).unwrap_err(), BlobDecodingError::ExtraneousData(10));
+        let mut blob = BlobData::encode(b"hello").unwrap();
+        blob[32] = 1;
+        assert_eq!(BlobData::decode(&blob).unwrap_err(), BlobDecodingError::ExtraneousData(32));
+
+        // Non-zero data past the decoded rounds.
+        let mut blob = BlobData::encode(b"hello").unwrap();
+        blob[BYTES_PER_BLOB - 1] = 1;
+        assert_eq!(
+            BlobData::decode(&blob).unwrap_err(),
+            BlobDecodingError::ExtraneousData(BYTES_PER_BLOB - 1)
+        );
+    }
+}
@@ -0,0 +1,462 @@
+//! Contains the [ChannelBank], a buffer of [Channel]s keyed by [ChannelId].
+
+use crate::{BlockInfo, Channel, ChannelError, ChannelId, Frame, FrameParseError};
+use alloc::collections::VecDeque;
+use alloy_primitives::{map::HashMap, Bytes};
+use maili_genesis::RollupConfig;
+use tracing::warn;
+
+/// [MAX_CHANNEL_BANK_SIZE] is the maximum combined size of all [Channel]s held by the
+/// [ChannelBank], in bytes, before the oldest channels are evicted.
+pub const MAX_CHANNEL_BANK_SIZE: usize = 100_000_000;
+
+/// [FJORD_MAX_CHANNEL_BANK_SIZE] is the maximum combined size of all [Channel]s held by the
+/// [ChannelBank], in bytes, once the Fjord hardfork is activated.
+pub const FJORD_MAX_CHANNEL_BANK_SIZE: usize = 1_000_000_000;
+
+/// An error returned when a [Frame] is not accepted by the [ChannelBank].
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChannelBankError {
+    /// The frame could not be parsed.
+    #[error("Failed to parse frame: {0}")]
+    Parse(#[from] FrameParseError),
+    /// The frame could not be added to its channel.
+    #[error("Failed to add frame to channel: {0}")]
+    Channel(#[from] ChannelError),
+    /// The channel that the frame belongs to is timed out.
+    #[error("Channel is timed out")]
+    ChannelTimedOut,
+    /// Holocene: a non-first frame was received without an open channel for it.
+    #[error("Received non-first frame {0} without an open channel")]
+    NoOpenChannel(u16),
+    /// Holocene: the frame was received out of order.
+    #[error("Frame number {0} received out of order, expected {1}")]
+    FrameOutOfOrder(u16, u16),
+    /// Holocene: the channel exceeded the max RLP bytes per channel and was dropped.
+    #[error("Channel exceeds the max RLP bytes per channel")]
+    ChannelTooLarge,
+}
+
+/// The [ChannelBank] buffers [Frame]s into [Channel]s until they are ready to be read.
+///
+/// Before Holocene, any number of channels may be open at once and frames may arrive in any
+/// order. Channels are kept in a FIFO queue, the oldest channels are evicted once the combined
+/// [Channel::size] exceeds the max channel bank size, and channels are dropped once they are
+/// older than the [RollupConfig::channel_timeout].
+///
+/// After Holocene, only a single channel is buffered at a time and its frames must arrive in
+/// order. A first frame always opens a new channel, dropping any incomplete one.
+#[derive(Debug, Clone)]
+pub struct ChannelBank<'a> {
+    /// A reference to the [RollupConfig] used for channel timeouts and hardfork rules.
+    config: &'a RollupConfig,
+    /// The buffered channels, keyed by [ChannelId].
+    channels: HashMap<ChannelId, Channel>,
+    /// The order in which channels were opened, oldest first.
+    channel_queue: VecDeque<ChannelId>,
+    /// Overrides the hardfork max channel bank size, if set.
+    max_size: Option<usize>,
+    /// Overrides the hardfork max RLP bytes per channel, if set.
+    max_rlp_bytes_per_channel: Option<u64>,
+}
+
+impl<'a> ChannelBank<'a> {
+    /// Creates a new, empty [ChannelBank].
+    pub fn new(config: &'a RollupConfig) -> Self {
+        Self {
+            config,
+            channels: HashMap::default(),
+            channel_queue: VecDeque::new(),
+            max_size: None,
+            max_rlp_bytes_per_channel: None,
+        }
+    }
+
+    /// Overrides the max channel bank size, which otherwise depends on the active hardfork.
+    pub const fn with_max_size(mut self, max_size: usize) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Overrides the Holocene max RLP bytes per channel, which otherwise depends on the active
+    /// hardfork.
+    pub const fn with_max_rlp_bytes_per_channel(mut self, max_rlp_bytes: u64) -> Self {
+        self.max_rlp_bytes_per_channel = Some(max_rlp_bytes);
+        self
+    }
+
+    /// Returns the number of buffered channels.
+    pub fn len(&self) -> usize {
+        self.channel_queue.len()
+    }
+
+    /// Returns if the channel bank is empty.
+    pub fn is_empty(&self) -> bool {
+        self.channel_queue.is_empty()
+    }
+
+    /// Returns the combined size of all buffered channels.
+    pub fn size(&self) -> usize {
+        self.channels.values().map(|c| c.size()).sum()
+    }
+
+    /// Returns the [Channel] with the given [ChannelId], if it is buffered.
+    pub fn channel(&self, id: &ChannelId) -> Option<&Channel> {
+        self.channels.get(id)
+    }
+
+    /// Returns the max channel bank size for the given timestamp.
+    pub fn max_size(&self, timestamp: u64) -> usize {
+        self.max_size.unwrap_or(if self.config.is_fjord_active(timestamp) {
+            FJORD_MAX_CHANNEL_BANK_SIZE
+        } else {
+            MAX_CHANNEL_BANK_SIZE
+        })
+    }
+
+    /// Removes all buffered channels.
+    pub fn clear(&mut self) {
+        self.channels.clear();
+        self.channel_queue.clear();
+    }
+
+    /// Returns `true` if the channel is timed out at the given L1 origin.
+    fn is_timed_out(&self, channel: &Channel, origin: &BlockInfo) -> bool {
+        channel.open_block_number() + self.config.channel_timeout(origin.timestamp) < origin.number
+    }
+
+    /// Evicts the oldest channels until the channel bank is within its max size.
+    pub fn prune(&mut self, timestamp: u64) {
+        let max_size = self.max_size(timestamp);
+        let mut total_size = self.size();
+        while total_size > max_size {
+            let Some(id) = self.channel_queue.pop_front() else {
+                break;
+            };
+            if let Some(channel) = self.channels.remove(&id) {
+                total_size -= channel.size();
+            }
+        }
+    }
+
+    /// Ingests all [Frame]s from the given iterator, such as a [crate::FrameIter].
+    ///
+    /// Frames that cannot be added to their channel are dropped, as in [Self::ingest_frame].
+    /// A frame that fails to parse stops ingestion, since the remaining data cannot be trusted.
+    ///
+    /// ## Returns
+    /// - `Ok(usize)`: The number of frames that were accepted
+    /// - `Err(_)`: If a frame failed to parse
+    pub fn ingest_frames<I>(
+        &mut self,
+        frames: I,
+        origin: BlockInfo,
+    ) -> Result<usize, ChannelBankError>
+    where
+        I: IntoIterator<Item = Result<Frame, FrameParseError>>,
+    {
+        let mut accepted = 0;
+        for frame in frames {
+            match self.ingest_frame(frame?, origin) {
+                Ok(()) => accepted += 1,
+                Err(e) => warn!(target: "channel-bank", "Dropping frame: {e}"),
+            }
+        }
+        Ok(accepted)
+    }
+
+    /// Ingests a single [Frame] included in the given L1 origin block.
+    ///
+    /// ## Returns
+    /// - `Ok(())`: If the frame was buffered
+    /// - `Err(_)`: If the frame was dropped
+    pub fn ingest_frame(
+        &mut self,
+        frame: Frame,
+        origin: BlockInfo,
+    ) -> Result<(), ChannelBankError> {
+        if self.config.is_holocene_active(origin.timestamp) {
+            return self.ingest_frame_holocene(frame, origin);
+        }
+
+        let id = frame.id;
+        if !self.channels.contains_key(&id) {
+            self.channels.insert(id, Channel::new(id, origin));
+            self.channel_queue.push_back(id);
+        }
+        let channel = &self.channels[&id];
+        if self.is_timed_out(channel, &origin) {
+            return Err(ChannelBankError::ChannelTimedOut);
+        }
+
+        if let Some(channel) = self.channels.get_mut(&id) {
+            channel.add_frame(frame, origin)?;
+        }
+        self.prune(origin.timestamp);
+        Ok(())
+    }
+
+    /// Ingests a [Frame] following the Holocene rules: a single channel with in-order frames.
+    fn ingest_frame_holocene(
+        &mut self,
+        frame: Frame,
+        origin: BlockInfo,
+    ) -> Result<(), ChannelBankError> {
+        // Drop the current channel if it timed out.
+        if let Some(channel) = self.current_channel() {
+            if self.is_timed_out(channel, &origin) {
+                self.clear();
+            }
+        }
+
+        // A first frame always opens a new channel, replacing any incomplete channel.
+        if frame.number == 0 {
+            self.clear();
+            self.channels.insert(frame.id, Channel::new(frame.id, origin));
+            self.channel_queue.push_back(frame.id);
+        }
+
+        let Some(channel) = self.channels.get_mut(&frame.id) else {
+            return Err(ChannelBankError::NoOpenChannel(frame.number));
+        };
+        let expected = channel.len() as u16;
+        if frame.number != expected {
+            return Err(ChannelBankError::FrameOutOfOrder(frame.number, expected));
+        }
+        channel.add_frame(frame, origin)?;
+
+        let max_rlp_bytes = self
+            .max_rlp_bytes_per_channel
+            .unwrap_or_else(|| self.config.max_rlp_bytes_per_channel(origin.timestamp));
+        if channel.size() as u64 > max_rlp_bytes {
+            self.clear();
+            return Err(ChannelBankError::ChannelTooLarge);
+        }
+        Ok(())
+    }
+
+    /// Returns the oldest buffered channel.
+    fn current_channel(&self) -> Option<&Channel> {
+        self.channel_queue.front().and_then(|id| self.channels.get(id))
+    }
+
+    /// Reads the data of the next ready channel at the given L1 origin, removing the channel from
+    /// the bank.
+    ///
+    /// Timed out channels at the front of the queue are dropped. Before Canyon, only the oldest
+    /// channel may be read. After Canyon, the first ready channel in the queue is read.
+    ///
+    /// ## Returns
+    /// - `Some(Bytes)`: The concatenated [Channel::frame_data] of the ready channel
+    /// - `None`: If no channel is ready
+    pub fn read(&mut self, origin: BlockInfo) -> Option<Bytes> {
+        // Drop any timed out channels at the front of the queue.
+        while let Some(channel) = self.current_channel() {
+            if !self.is_timed_out(channel, &origin) {
+                break;
+            }
+            if let Some(id) = self.channel_queue.pop_front() {
+                self.channels.remove(&id);
+            }
+        }
+
+        let index = if self.config.is_canyon_active(origin.timestamp) {
+            self.channel_queue.iter().position(|id| self.channels[id].is_ready())?
+        } else {
+            0
+        };
+
+        let channel = self.channels.get(self.channel_queue.get(index)?)?;
+        if self.is_timed_out(channel, &origin) || !channel.is_ready() {
+            return None;
+        }
+
+        let id = self.channel_queue.remove(index)?;
+        self.channels.remove(&id)?.frame_data()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FrameIter, DERIVATION_VERSION_0};
+    use alloc::vec;
+
+    fn frame(id: ChannelId, number: u16, data: &[u8], is_last: bool) -> Frame {
+        Frame { id, number, data: data.to_vec(), is_last }
+    }
+
+    fn origin(number: u64) -> BlockInfo {
+        BlockInfo { number, ..Default::default() }
+    }
+
+    #[test]
+    fn test_channel_bank_accessors() {
+        let config = RollupConfig::default();
+        let bank = ChannelBank::new(&config);
+        assert!(bank.is_empty());
+        assert_eq!(bank.len(), 0);
+        assert_eq!(bank.size(), 0);
+        assert_eq!(bank.max_size(0), MAX_CHANNEL_BANK_SIZE);
+
+        let config = RollupConfig { fjord_time: Some(0), ..Default::default() };
+        let bank = ChannelBank::new(&config);
+        assert_eq!(bank.max_size(0), FJORD_MAX_CHANNEL_BANK_SIZE);
+    }
+
+    #[test]
+    fn test_ingest_and_read_out_of_order() {
+        let config = RollupConfig { channel_timeout: 10, ..Default::default() };
+        let mut bank = ChannelBank::new(&config);
+
+        bank.ingest_frame(frame([0xFF; 16], 1, b"four", true), origin(0)).unwrap();
+        assert_eq!(bank.read(origin(0)), None);
+        bank.ingest_frame(frame([0xFF; 16], 0, b"seven", false), origin(1)).unwrap();
+        assert_eq!(bank.len(), 1);
+        assert_eq!(bank.size(), 409);
+
+        assert_eq!(bank.read(origin(1)), Some(Bytes::from_static(b"sevenfour")));
+        assert!(bank.is_empty());
+        assert_eq!(bank.read(origin(1)), None);
+    }
+
+    #[test]
+    fn test_ingest_frame_channel_error() {
+        let config = RollupConfig::default();
+        let mut bank = ChannelBank::new(&config);
+
+        bank.ingest_frame(frame([0xFF; 16], 0, b"four", false), origin(0)).unwrap();
+        let err = bank.ingest_frame(frame([0xFF; 16], 0, b"four", false), origin(0)).unwrap_err();
+        assert_eq!(err, ChannelBankError::Channel(ChannelError::FrameNumberExists(0)));
+    }
+
+    #[test]
+    fn test_ingest_frame_timed_out() {
+        let config = RollupConfig { channel_timeout: 2, ..Default::default() };
+        let mut bank = ChannelBank::new(&config);
+
+        bank.ingest_frame(frame([0xFF; 16], 0, b"four", false), origin(0)).unwrap();
+        let err = bank.ingest_frame(frame([0xFF; 16], 1, b"four", true), origin(3)).unwrap_err();
+        assert_eq!(err, ChannelBankError::ChannelTimedOut);
+
+        // The timed out channel is dropped on the next read.
+        assert_eq!(bank.read(origin(3)), None);
+        assert!(bank.is_empty());
+    }
+
+    #[test]
+    fn test_read_pre_canyon_blocks_on_first_channel() {
+        let config = RollupConfig { channel_timeout: 10, ..Default::default() };
+        let mut bank = ChannelBank::new(&config);
+
+        bank.ingest_frame(frame([0xAA; 16], 0, b"a", false), origin(0)).unwrap();
+        bank.ingest_frame(frame([0xBB; 16], 0, b"b", true), origin(0)).unwrap();
+        assert_eq!(bank.read(origin(0)), None);
+        assert_eq!(bank.len(), 2);
+    }
+
+    #[test]
+    fn test_read_post_canyon_first_ready_channel() {
+        let config =
+            RollupConfig { channel_timeout: 10, canyon_time: Some(0), ..Default::default() };
+        let mut bank = ChannelBank::new(&config);
+
+        bank.ingest_frame(frame([0xAA; 16], 0, b"a", false), origin(0)).unwrap();
+        bank.ingest_frame(frame([0xBB; 16], 0, b"b", true), origin(0)).unwrap();
+        assert_eq!(bank.read(origin(0)), Some(Bytes::from_static(b"b")));
+        assert_eq!(bank.len(), 1);
+        assert!(bank.channel(&[0xAA; 16]).is_some());
+    }
+
+    #[test]
+    fn test_prune_evicts_oldest_channel() {
+        let config = RollupConfig::default();
+        let mut bank = ChannelBank::new(&config).with_max_size(1_000);
+        assert_eq!(bank.max_size(0), 1_000);
+
+        // Each channel holds the 200 bytes of frame overhead plus its data.
+        let data = vec![0xDD; 400];
+        bank.ingest_frame(frame([0xAA; 16], 0, &data, false), origin(0)).unwrap();
+        bank.ingest_frame(frame([0xBB; 16], 0, &data, false), origin(0)).unwrap();
+        assert_eq!(bank.len(), 1);
+        assert!(bank.channel(&[0xAA; 16]).is_none());
+        assert!(bank.channel(&[0xBB; 16]).is_some());
+    }
+
+    #[test]
+    fn test_ingest_frames_from_iter() {
+        let config = RollupConfig::default();
+        let mut bank = ChannelBank::new(&config);
+
+        let mut bytes = vec![DERIVATION_VERSION_0];
+        bytes.extend(frame([0xFF; 16], 0, b"seven", false).encode());
+        bytes.extend(frame([0xFF; 16], 1, b"four", true).encode());
+        // A duplicate frame is dropped without stopping ingestion.
+        bytes.extend(frame([0xFF; 16], 1, b"four", true).encode());
+
+        let accepted = bank.ingest_frames(FrameIter::new(&bytes), origin(0)).unwrap();
+        assert_eq!(accepted, 2);
+        assert_eq!(bank.read(origin(0)), Some(Bytes::from_static(b"sevenfour")));
+    }
+
+    #[test]
+    fn test_holocene_in_order_frames() {
+        let config = RollupConfig { holocene_time: Some(0), ..Default::default() };
+        let mut bank = ChannelBank::new(&config);
+
+        let err = bank.ingest_frame(frame([0xFF; 16], 1, b"four", true), origin(0)).unwrap_err();
+        assert_eq!(err, ChannelBankError::NoOpenChannel(1));
+
+        bank.ingest_frame(frame([0xFF; 16], 0, b"seven", false), origin(0)).unwrap();
+        let err = bank.ingest_frame(frame([0xFF; 16], 2, b"four", true), origin(0)).unwrap_err();
+        assert_eq!(err, ChannelBankError::FrameOutOfOrder(2, 1));
+        bank.ingest_frame(frame([0xFF; 16], 1, b"four", true), origin(0)).unwrap();
+
+        assert_eq!(bank.read(origin(0)), Some(Bytes::from_static(b"sevenfour")));
+        assert!(bank.is_empty());
+    }
+
+    #[test]
+    fn test_holocene_first_frame_replaces_channel() {
+        let config = RollupConfig { holocene_time: Some(0), ..Default::default() };
+        let mut bank = ChannelBank::new(&config);
+
+        bank.ingest_frame(frame([0xAA; 16], 0, b"a", false), origin(0)).unwrap();
+        bank.ingest_frame(frame([0xBB; 16], 0, b"b", true), origin(0)).unwrap();
+        assert_eq!(bank.len(), 1);
+        assert!(bank.channel(&[0xAA; 16]).is_none());
+
+        let err = bank.ingest_frame(frame([0xAA; 16], 1, b"a", true), origin(0)).unwrap_err();
+        assert_eq!(err, ChannelBankError::NoOpenChannel(1));
+        assert_eq!(bank.read(origin(0)), Some(Bytes::from_static(b"b")));
+    }
+
+    #[test]
+    fn test_holocene_channel_timed_out() {
+        let config = RollupConfig {
+            holocene_time: Some(0),
+            granite_channel_timeout: 2,
+            ..Default::default()
+        };
+        let mut bank = ChannelBank::new(&config);
+
+        bank.ingest_frame(frame([0xFF; 16], 0, b"seven", false), origin(0)).unwrap();
+        let err = bank.ingest_frame(frame([0xFF; 16], 1, b"four", true), origin(3)).unwrap_err();
+        assert_eq!(err, ChannelBankError::NoOpenChannel(1));
+        assert!(bank.is_empty());
+    }
+
+    #[test]
+    fn test_holocene_channel_too_large() {
+        let config = RollupConfig { holocene_time: Some(0), ..Default::default() };
+        let mut bank = ChannelBank::new(&config).with_max_rlp_bytes_per_channel(1_000);
+
+        let data = vec![0xDD; 100];
+        for i in 0..3 {
+            bank.ingest_frame(frame([0xFF; 16], i, &data, false), origin(0)).unwrap();
+        }
+        let err = bank.ingest_frame(frame([0xFF; 16], 3, &data, true), origin(0)).unwrap_err();
+        assert_eq!(err, ChannelBankError::ChannelTooLarge);
+        assert!(bank.is_empty());
+    }
+}
@@ -3,7 +3,7 @@
 use crate::{Batch, ChannelCompressor, ChannelId, CompressorError, Frame};
 use alloc::{vec, vec::Vec};
 use maili_genesis::RollupConfig;
-use rand::{rngs::SmallRng, RngCore, SeedableRng};
+use rand::RngCore;
 
 /// The frame overhead.
 const FRAME_V0_OVERHEAD: usize = 23;
@@ -62,17 +62,24 @@ where
         Self { id, config, rlp_length: 0, frame_number: 0, closed: false, compressor }
     }
 
-    /// Resets the [ChannelOut] to its initial state.
-    pub fn reset(&mut self) {
+    /// Resets the [ChannelOut] to its initial state, drawing a new random [ChannelId] from the
+    /// given entropy source.
+    ///
+    /// `getrandom` isn't available for wasm and risc targets, and thread-based RNGs are not
+    /// available for `no_std`, so the caller must supply the RNG.
+    pub fn reset<R: RngCore>(&mut self, rng: &mut R) {
+        let mut id = ChannelId::default();
+        rng.fill_bytes(&mut id);
+        self.reset_with_id(id);
+    }
+
+    /// Resets the [ChannelOut] to its initial state with the given [ChannelId].
+    pub fn reset_with_id(&mut self, id: ChannelId) {
+        self.id = id;
         self.rlp_length = 0;
         self.frame_number = 0;
         self.closed = false;
         self.compressor.reset();
-        // `getrandom` isn't available for wasm and risc targets
-        // Thread-based RNGs are not available for no_std
-        // So we must use a seeded RNG.
-        let mut small_rng = SmallRng::seed_from_u64(43);
-        SmallRng::fill_bytes(&mut small_rng, &mut self.id);
     }
 
     /// Accepts the given [crate::Batch] data into the [ChannelOut], compressing it
@@ -81,6 +88,9 @@ where
         if self.closed {
             return Err(ChannelOutError::ChannelClosed);
         }
+        if self.is_full() {
+            return Err(ChannelOutError::Compression(CompressorError::Full));
+        }
 
         // Encode the batch.
         let mut buf = vec![];
@@ -93,10 +103,26 @@ where
         }
 
         self.compressor.write(&buf)?;
+        self.rlp_length += buf.len() as u64;
 
         Ok(())
     }
 
+    /// Returns if the channel is full and should be closed.
+    ///
+    /// The channel is full once its compressor has exhausted its size budget.
+    pub fn is_full(&self) -> bool {
+        self.compressor.is_full()
+    }
+
+    /// Returns if the channel has compressed data ready to be output as frames.
+    ///
+    /// A [ChannelOut] should be closed once it [is full](Self::is_full), after which all ready
+    /// data can be drained with [Self::output_frame].
+    pub fn is_ready(&self) -> bool {
+        self.ready_bytes() > 0
+    }
+
     /// Returns the total amount of rlp-encoded input bytes.
     pub const fn input_bytes(&self) -> u64 {
         self.rlp_length
@@ -148,6 +174,7 @@ where
 mod tests {
     use super::*;
     use crate::{test_utils::MockCompressor, CompressorWriter, SingleBatch, SpanBatch};
+    use rand::{rngs::SmallRng, SeedableRng};
 
     #[test]
     fn test_output_frame_max_size_too_small() {
@@ -162,7 +189,11 @@ mod tests {
         let mut channel = ChannelOut::new(
             ChannelId::default(),
             &config,
-            MockCompressor { read_error: true, compressed: Some(Default::default()) },
+            MockCompressor {
+                read_error: true,
+                compressed: Some(Default::default()),
+                ..Default::default()
+            },
         );
         let err = channel.output_frame(FRAME_V0_OVERHEAD).unwrap_err();
         assert_eq!(err, ChannelOutError::Compression(CompressorError::Full));
@@ -193,7 +224,7 @@ mod tests {
             frame_number: 11,
             compressor: MockCompressor::default(),
         };
-        channel.reset();
+        channel.reset(&mut SmallRng::seed_from_u64(43));
         assert_eq!(channel.rlp_length, 0);
         assert_eq!(channel.frame_number, 0);
         // The odds of a randomized channel id being equal to the
@@ -203,6 +234,59 @@ mod tests {
         assert!(!channel.closed);
     }
 
+    #[test]
+    fn test_channel_out_reset_unique_ids() {
+        let config = RollupConfig::default();
+        let mut channel = ChannelOut::new(ChannelId::default(), &config, MockCompressor::default());
+        let mut rng = SmallRng::seed_from_u64(43);
+
+        channel.reset(&mut rng);
+        let first = channel.id;
+        channel.reset(&mut rng);
+        assert_ne!(channel.id, first);
+    }
+
+    #[test]
+    fn test_channel_out_reset_with_id() {
+        let config = RollupConfig::default();
+        let mut channel = ChannelOut::new(ChannelId::default(), &config, MockCompressor::default());
+        channel.rlp_length = 10;
+        channel.close();
+
+        channel.reset_with_id([0xFF; 16]);
+        assert_eq!(channel.id, [0xFF; 16]);
+        assert_eq!(channel.rlp_length, 0);
+        assert!(!channel.closed);
+    }
+
+    #[test]
+    fn test_channel_out_is_full() {
+        let config = RollupConfig::default();
+        let mut channel = ChannelOut::new(
+            ChannelId::default(),
+            &config,
+            MockCompressor { is_full: true, ..Default::default() },
+        );
+        assert!(channel.is_full());
+
+        let batch = Batch::Single(SingleBatch::default());
+        assert_eq!(
+            channel.add_batch(batch),
+            Err(ChannelOutError::Compression(CompressorError::Full))
+        );
+    }
+
+    #[test]
+    fn test_channel_out_is_ready() {
+        let config = RollupConfig::default();
+        let mut channel = ChannelOut::new(ChannelId::default(), &config, MockCompressor::default());
+        assert!(!channel.is_ready());
+
+        channel.add_batch(Batch::Single(SingleBatch::default())).unwrap();
+        assert!(channel.is_ready());
+        assert!(!channel.is_full());
+    }
+
     #[test]
     fn test_channel_out_ready_bytes_empty() {
         let config = RollupConfig::default();
@@ -266,4 +350,25 @@ mod tests {
         let batch = Batch::Single(SingleBatch::default());
         assert_eq!(channel.add_batch(batch), Ok(()));
     }
+
+    #[test]
+    fn test_channel_out_add_batch_tracks_rlp_length() {
+        let config = RollupConfig::default();
+        let mut channel = ChannelOut::new(ChannelId::default(), &config, MockCompressor::default());
+
+        let batch = Batch::Single(SingleBatch::default());
+        let mut buf = vec![];
+        batch.encode(&mut buf).unwrap();
+
+        channel.add_batch(batch.clone()).unwrap();
+        assert_eq!(channel.input_bytes(), buf.len() as u64);
+        channel.add_batch(batch.clone()).unwrap();
+        assert_eq!(channel.input_bytes(), 2 * buf.len() as u64);
+
+        // Once the accepted RLP bytes reach the limit, further batches are rejected.
+        let max = config.max_rlp_bytes_per_channel(batch.timestamp());
+        channel.rlp_length = max - buf.len() as u64 + 1;
+        assert_eq!(channel.add_batch(batch), Err(ChannelOutError::ExceedsMaxRlpBytesPerChannel));
+        assert_eq!(channel.input_bytes(), max - buf.len() as u64 + 1);
+    }
 }
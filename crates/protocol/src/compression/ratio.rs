@@ -77,6 +77,10 @@ impl CompressorWriter for RatioCompressor {
         self.lake = 0;
     }
 
+    fn is_full(&self) -> bool {
+        Self::is_full(self)
+    }
+
     fn len(&self) -> usize {
         self.compressor.len()
     }
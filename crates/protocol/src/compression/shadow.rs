@@ -96,6 +96,10 @@ impl CompressorWriter for ShadowCompressor {
         self.compressor.len()
     }
 
+    fn is_full(&self) -> bool {
+        self.is_full
+    }
+
     fn flush(&mut self) -> CompressorResult<()> {
         self.shadow.flush()
     }
@@ -25,6 +25,13 @@ pub trait CompressorWriter {
     /// Returns the length of the compressed data.
     fn len(&self) -> usize;
 
+    /// Returns if the compressor is full and can not accept more data.
+    ///
+    /// Compressors without a size budget are never full.
+    fn is_full(&self) -> bool {
+        false
+    }
+
     /// Reads the compressed data into the given buffer.
     /// Returns the number of bytes read.
     fn read(&mut self, buf: &mut [u8]) -> CompressorResult<usize>;
@@ -1,6 +1,7 @@
 //! Contains deposit transaction types and helper methods.
 
 use alloc::vec::Vec;
+use alloy_consensus::{Eip658Value, Receipt};
 use alloy_eips::eip2718::Encodable2718;
 use alloy_primitives::{b256, Address, Bytes, Log, TxKind, B256, U256, U64};
 use op_alloy_consensus::{TxDeposit, UserDepositSource};
@@ -184,6 +185,31 @@ pub fn decode_deposit(block_hash: B256, index: usize, log: &Log) -> Result<Bytes
     Ok(Bytes::from(buffer))
 }
 
+/// Derives the user deposit transactions from the receipts of an L1 block.
+///
+/// Deposits are decoded from the `TransactionDeposited` events that the deposit contract emitted
+/// in successful transactions, in the order they were emitted. Each deposit's source hash commits
+/// to the index of its log within the block, counting the logs of all successful receipts.
+pub fn derive_deposits(
+    block_hash: B256,
+    receipts: &[Receipt],
+    deposit_contract: Address,
+) -> Result<Vec<Bytes>, DepositError> {
+    let mut deposits = Vec::new();
+    let logs = receipts
+        .iter()
+        .filter(|receipt| receipt.status != Eip658Value::Eip658(false))
+        .flat_map(|receipt| receipt.logs.iter());
+    for (index, log) in logs.enumerate() {
+        if log.address == deposit_contract
+            && log.topics().first().is_some_and(|topic| *topic == DEPOSIT_EVENT_ABI_HASH)
+        {
+            deposits.push(decode_deposit(block_hash, index, log)?);
+        }
+    }
+    Ok(deposits)
+}
+
 /// Unmarshals a deposit transaction from the opaque data.
 pub(crate) fn unmarshal_deposit_version0(
     tx: &mut TxDeposit,
@@ -448,6 +474,71 @@ mod test {
         assert_eq!(tx, expected);
     }
 
+    fn deposit_log(address: Address) -> Log {
+        let valid_to = b256!("000000000000000000000000bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb");
+        let valid_from = b256!("000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF");
+        let mut data = vec![0u8; 192];
+        data[24..32].copy_from_slice(&U64::from(32).to_be_bytes::<8>());
+        data[56..64].copy_from_slice(&U64::from(128).to_be_bytes::<8>());
+        Log {
+            address,
+            data: LogData::new_unchecked(
+                vec![DEPOSIT_EVENT_ABI_HASH, valid_from, valid_to, B256::default()],
+                Bytes::from(data),
+            ),
+        }
+    }
+
+    #[test]
+    fn test_derive_deposits() {
+        let deposit_contract = address!("bEb5Fc579115071764c7423A4f12eDde41f106Ed");
+        let other_log = Log { address: deposit_contract, data: LogData::default() };
+        let receipts = vec![
+            Receipt {
+                status: Eip658Value::Eip658(true),
+                cumulative_gas_used: 0,
+                logs: vec![other_log, deposit_log(deposit_contract)],
+            },
+            // Logs of failed transactions are skipped, and not counted.
+            Receipt {
+                status: Eip658Value::Eip658(false),
+                cumulative_gas_used: 0,
+                logs: vec![deposit_log(deposit_contract)],
+            },
+            Receipt {
+                status: Eip658Value::Eip658(true),
+                cumulative_gas_used: 0,
+                logs: vec![deposit_log(Address::ZERO), deposit_log(deposit_contract)],
+            },
+        ];
+
+        let block_hash = B256::repeat_byte(0x01);
+        let deposits = derive_deposits(block_hash, &receipts, deposit_contract).unwrap();
+        assert_eq!(
+            deposits,
+            vec![
+                decode_deposit(block_hash, 1, &deposit_log(deposit_contract)).unwrap(),
+                decode_deposit(block_hash, 3, &deposit_log(deposit_contract)).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_derive_deposits_invalid_deposit() {
+        let deposit_contract = address!("bEb5Fc579115071764c7423A4f12eDde41f106Ed");
+        let mut log = deposit_log(deposit_contract);
+        log.data = LogData::new_unchecked(log.topics().to_vec(), Bytes::from(vec![0u8; 63]));
+        let receipts = vec![Receipt {
+            status: Eip658Value::Eip658(true),
+            cumulative_gas_used: 0,
+            logs: vec![log],
+        }];
+        assert_eq!(
+            derive_deposits(B256::ZERO, &receipts, deposit_contract),
+            Err(DepositError::IncompleteOpaqueData(63))
+        );
+    }
+
     #[test]
     fn test_unmarshal_deposit_version0_invalid_len() {
         let data = vec![0u8; 72];
@@ -1,7 +1,9 @@
 //! This module contains the L1 block fee calculation function.
 
+use crate::{starts_with_2718_deposit, L1BlockInfoTx};
 use alloy_primitives::U256;
 use core::ops::Mul;
+use maili_genesis::RollupConfig;
 
 /// Re-export the fastlz compression length calculation function.
 pub use op_alloy_flz::flz_compress_len;
@@ -192,9 +194,190 @@ fn calculate_l1_fee_scaled_ecotone(
     U256::from(calldata_cost_per_byte).saturating_add(blob_cost_per_byte)
 }
 
+/// The L1 cost formula in effect for a block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum L1CostFormula {
+    Bedrock,
+    Regolith,
+    /// The Ecotone activation block, which still uses the Bedrock formula.
+    EcotoneEmptyScalars,
+    Ecotone,
+    Fjord,
+}
+
+/// The fees charged to a transaction on top of its L2 execution gas.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct L1FeeBreakdown {
+    /// The L1 data fee, as returned by `GasPriceOracle.getL1Fee`.
+    pub l1_fee: U256,
+    /// The L1 gas used by the transaction data, as returned by `GasPriceOracle.getL1GasUsed`.
+    pub l1_gas_used: U256,
+    /// The estimated compressed size of the transaction, in bytes.
+    pub estimated_size: u64,
+    /// The operator fee, charged since Isthmus.
+    pub operator_fee: U256,
+}
+
+impl L1FeeBreakdown {
+    /// Returns the sum of the L1 data fee and the operator fee.
+    pub const fn total(&self) -> U256 {
+        self.l1_fee.saturating_add(self.operator_fee)
+    }
+}
+
+/// Computes the L1 data fee and operator fee of transactions included in an L2 block.
+///
+/// The fee parameters are read from the [L1BlockInfoTx] of the block, and the cost formula is
+/// chosen from the hardforks of the [RollupConfig] active at the block timestamp. Deposit
+/// transactions are never charged.
+#[derive(Debug, Clone, Copy)]
+pub struct L1FeeCalculator<'a> {
+    /// The rollup config.
+    config: &'a RollupConfig,
+    /// The L1 info transaction of the block.
+    l1_info: &'a L1BlockInfoTx,
+}
+
+impl<'a> L1FeeCalculator<'a> {
+    /// Creates a new [L1FeeCalculator].
+    pub const fn new(config: &'a RollupConfig, l1_info: &'a L1BlockInfoTx) -> Self {
+        Self { config, l1_info }
+    }
+
+    /// Returns the cost formula in effect at the given L2 block timestamp.
+    fn formula(&self, timestamp: u64) -> L1CostFormula {
+        if self.config.is_fjord_active(timestamp) {
+            L1CostFormula::Fjord
+        } else if self.config.is_ecotone_active(timestamp) {
+            if self.l1_info.empty_scalars() {
+                L1CostFormula::EcotoneEmptyScalars
+            } else {
+                L1CostFormula::Ecotone
+            }
+        } else if self.config.is_regolith_active(timestamp) {
+            L1CostFormula::Regolith
+        } else {
+            L1CostFormula::Bedrock
+        }
+    }
+
+    /// Returns the L1 data fee of the raw EIP-2718 encoded transaction, included in a block with
+    /// the given timestamp.
+    pub fn l1_fee(&self, tx: &[u8], timestamp: u64) -> U256 {
+        if tx.is_empty() || starts_with_2718_deposit(&tx) {
+            return U256::ZERO;
+        }
+
+        let info = self.l1_info;
+        match self.formula(timestamp) {
+            L1CostFormula::Bedrock => calculate_tx_l1_cost_bedrock(
+                tx,
+                info.l1_fee_overhead(),
+                info.l1_base_fee(),
+                info.l1_fee_scalar(),
+            ),
+            L1CostFormula::Regolith => calculate_tx_l1_cost_regolith(
+                tx,
+                info.l1_fee_overhead(),
+                info.l1_base_fee(),
+                info.l1_fee_scalar(),
+            ),
+            L1CostFormula::EcotoneEmptyScalars => calculate_tx_l1_cost_bedrock_empty_scalars(
+                tx,
+                info.l1_fee_overhead(),
+                info.l1_base_fee(),
+                info.l1_fee_scalar(),
+            ),
+            L1CostFormula::Ecotone => calculate_tx_l1_cost_ecotone(
+                tx,
+                info.l1_base_fee(),
+                info.l1_fee_scalar(),
+                info.blob_base_fee(),
+                info.blob_base_fee_scalar(),
+            ),
+            L1CostFormula::Fjord => calculate_tx_l1_cost_fjord(
+                tx,
+                info.l1_base_fee(),
+                info.l1_fee_scalar(),
+                info.blob_base_fee(),
+                info.blob_base_fee_scalar(),
+            ),
+        }
+    }
+
+    /// Returns the L1 gas used by the raw EIP-2718 encoded transaction, included in a block with
+    /// the given timestamp.
+    ///
+    /// Before Ecotone, this includes the L1 fee overhead.
+    pub fn l1_gas_used(&self, tx: &[u8], timestamp: u64) -> U256 {
+        if tx.is_empty() || starts_with_2718_deposit(&tx) {
+            return U256::ZERO;
+        }
+
+        match self.formula(timestamp) {
+            L1CostFormula::Bedrock => {
+                data_gas_bedrock(tx).saturating_add(self.l1_info.l1_fee_overhead())
+            }
+            L1CostFormula::Regolith | L1CostFormula::EcotoneEmptyScalars => {
+                data_gas_regolith(tx).saturating_add(self.l1_info.l1_fee_overhead())
+            }
+            L1CostFormula::Ecotone => data_gas_regolith(tx),
+            L1CostFormula::Fjord => data_gas_fjord(tx),
+        }
+    }
+
+    /// Returns the estimated compressed size in bytes of the raw EIP-2718 encoded transaction,
+    /// included in a block with the given timestamp.
+    ///
+    /// Since Fjord, this is the FastLZ based estimate. Before, the calldata gas is used as a proxy
+    /// of the compressed size.
+    pub fn estimated_size(&self, tx: &[u8], timestamp: u64) -> u64 {
+        if tx.is_empty() || starts_with_2718_deposit(&tx) {
+            return 0;
+        }
+
+        match self.formula(timestamp) {
+            L1CostFormula::Fjord => tx_estimated_size_fjord(tx) / 1_000_000,
+            _ => data_gas_regolith(tx).saturating_to::<u64>() / NON_ZERO_BYTE_COST,
+        }
+    }
+
+    /// Returns the operator fee of a transaction using `gas_used` gas, included in a block with
+    /// the given timestamp.
+    ///
+    /// The operator fee is `gas_used * operator_fee_scalar / 1e6 + operator_fee_constant`, and is
+    /// only charged since Isthmus.
+    pub fn operator_fee(&self, gas_used: u64, timestamp: u64) -> U256 {
+        if !self.config.is_isthmus_active(timestamp) {
+            return U256::ZERO;
+        }
+
+        U256::from(gas_used)
+            .saturating_mul(U256::from(self.l1_info.operator_fee_scalar()))
+            .wrapping_div(U256::from(1_000_000))
+            .saturating_add(U256::from(self.l1_info.operator_fee_constant()))
+    }
+
+    /// Returns the [L1FeeBreakdown] of the raw EIP-2718 encoded transaction using `gas_used` gas,
+    /// included in a block with the given timestamp.
+    pub fn breakdown(&self, tx: &[u8], gas_used: u64, timestamp: u64) -> L1FeeBreakdown {
+        if starts_with_2718_deposit(&tx) {
+            return L1FeeBreakdown::default();
+        }
+
+        L1FeeBreakdown {
+            l1_fee: self.l1_fee(tx, timestamp),
+            l1_gas_used: self.l1_gas_used(tx, timestamp),
+            estimated_size: self.estimated_size(tx, timestamp),
+            operator_fee: self.operator_fee(gas_used, timestamp),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{L1BlockInfoBedrock, L1BlockInfoEcotone, L1BlockInfoIsthmus};
     use alloy_primitives::{bytes, hex};
 
     #[test]
@@ -460,4 +643,127 @@ mod tests {
         );
         assert_eq!(l1_fee, expected_l1_fee)
     }
+
+    fn fork_config() -> RollupConfig {
+        RollupConfig {
+            regolith_time: Some(10),
+            ecotone_time: Some(20),
+            fjord_time: Some(30),
+            isthmus_time: Some(40),
+            ..Default::default()
+        }
+    }
+
+    fn bedrock_info() -> L1BlockInfoTx {
+        L1BlockInfoTx::Bedrock(L1BlockInfoBedrock {
+            base_fee: 1_000,
+            l1_fee_overhead: U256::from(1_000),
+            l1_fee_scalar: U256::from(1_000),
+            ..Default::default()
+        })
+    }
+
+    fn isthmus_info() -> L1BlockInfoTx {
+        L1BlockInfoTx::Isthmus(L1BlockInfoIsthmus {
+            base_fee: 1_000,
+            base_fee_scalar: 1_000,
+            blob_base_fee: 1_000,
+            blob_base_fee_scalar: 1_000,
+            operator_fee_scalar: 2_000_000,
+            operator_fee_constant: 500,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_l1_fee_calculator_bedrock_regolith() {
+        let config = fork_config();
+        let info = bedrock_info();
+        let calculator = L1FeeCalculator::new(&config, &info);
+        let input = bytes!("FACADE");
+
+        // (16 * 3 + 16 * 68 + 1000) * 1000 * 1000 / 1e6
+        assert_eq!(calculator.l1_fee(&input, 0), U256::from(2136));
+        assert_eq!(calculator.l1_gas_used(&input, 0), U256::from(2136));
+        // (16 * 3 + 1000) * 1000 * 1000 / 1e6
+        assert_eq!(calculator.l1_fee(&input, 10), U256::from(1048));
+        assert_eq!(calculator.l1_gas_used(&input, 10), U256::from(1048));
+        assert_eq!(calculator.estimated_size(&input, 10), 3);
+        assert_eq!(calculator.operator_fee(21_000, 10), U256::ZERO);
+    }
+
+    #[test]
+    fn test_l1_fee_calculator_ecotone_empty_scalars() {
+        let config = fork_config();
+        let info = L1BlockInfoTx::Ecotone(L1BlockInfoEcotone {
+            base_fee: 1_000,
+            empty_scalars: true,
+            l1_fee_overhead: U256::from(1_000),
+            ..Default::default()
+        });
+        let calculator = L1FeeCalculator::new(&config, &info);
+        let input = bytes!("FACADE");
+
+        let expected = calculate_tx_l1_cost_bedrock_empty_scalars(
+            &input,
+            info.l1_fee_overhead(),
+            info.l1_base_fee(),
+            info.l1_fee_scalar(),
+        );
+        assert_eq!(calculator.l1_fee(&input, 20), expected);
+    }
+
+    #[test]
+    fn test_l1_fee_calculator_ecotone_fjord() {
+        let config = fork_config();
+        let info = isthmus_info();
+        let calculator = L1FeeCalculator::new(&config, &info);
+        let input = bytes!("FACADE");
+
+        // (16 * 3) * (1000 * 16 * 1000 + 1000 * 1000) / (16 * 1e6)
+        assert_eq!(calculator.l1_fee(&input, 20), U256::from(51));
+        assert_eq!(calculator.l1_gas_used(&input, 20), U256::from(48));
+        assert_eq!(calculator.estimated_size(&input, 20), 3);
+
+        // 100 * (1000 * 16 * 1000 + 1000 * 1000) / 1e6
+        assert_eq!(calculator.l1_fee(&input, 30), U256::from(1700));
+        assert_eq!(calculator.l1_gas_used(&input, 30), U256::from(1600));
+        assert_eq!(calculator.estimated_size(&input, 30), 100);
+    }
+
+    #[test]
+    fn test_l1_fee_calculator_operator_fee() {
+        let config = fork_config();
+        let info = isthmus_info();
+        let calculator = L1FeeCalculator::new(&config, &info);
+        let input = bytes!("FACADE");
+
+        assert_eq!(calculator.operator_fee(21_000, 39), U256::ZERO);
+        // 21000 * 2_000_000 / 1e6 + 500
+        assert_eq!(calculator.operator_fee(21_000, 40), U256::from(42_500));
+
+        let breakdown = calculator.breakdown(&input, 21_000, 40);
+        assert_eq!(
+            breakdown,
+            L1FeeBreakdown {
+                l1_fee: U256::from(1700),
+                l1_gas_used: U256::from(1600),
+                estimated_size: 100,
+                operator_fee: U256::from(42_500),
+            }
+        );
+        assert_eq!(breakdown.total(), U256::from(44_200));
+    }
+
+    #[test]
+    fn test_l1_fee_calculator_deposit() {
+        let config = fork_config();
+        let info = isthmus_info();
+        let calculator = L1FeeCalculator::new(&config, &info);
+        let input = bytes!("7EFACADE");
+
+        assert_eq!(calculator.breakdown(&input, 21_000, 40), L1FeeBreakdown::default());
+        assert_eq!(calculator.l1_fee(&input, 40), U256::ZERO);
+        assert_eq!(calculator.l1_gas_used(&input, 0), U256::ZERO);
+    }
 }
@@ -0,0 +1,326 @@
+//! Contains the [FrameDecoder], an incremental decoder of [Frame]s from batcher transaction data.
+
+use crate::{Frame, FrameDecodingError, DERIVATION_VERSION_0, MAX_FRAME_LEN};
+use alloc::vec::Vec;
+
+/// The length of the frame header: `channel_id ++ frame_number ++ frame_data_length`.
+const FRAME_HEADER_LEN: usize = 16 + 2 + 4;
+
+/// An error returned by the [FrameDecoder].
+///
+/// Offsets are byte offsets into the batcher transaction data, where offset `0` is the
+/// derivation version byte.
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FrameDecoderError {
+    /// The derivation version byte is not supported.
+    #[error("Unsupported derivation version: {0}")]
+    UnsupportedVersion(u8),
+    /// The frame starting at the given offset failed to decode.
+    #[error("Frame decoding error at offset {offset}: {error}")]
+    Frame {
+        /// The offset of the first byte of the frame.
+        offset: usize,
+        /// The underlying [FrameDecodingError].
+        error: FrameDecodingError,
+    },
+    /// The data ended in the middle of the frame starting at the given offset.
+    #[error("Data ended with an incomplete frame at offset {offset} ({buffered} bytes buffered)")]
+    IncompleteFrame {
+        /// The offset of the first byte of the incomplete frame.
+        offset: usize,
+        /// The number of bytes of the incomplete frame that were received.
+        buffered: usize,
+    },
+    /// The data ended without any frames.
+    #[error("No frames decoded")]
+    NoFrames,
+}
+
+/// The [FrameDecoder] incrementally decodes [Frame]s from batcher transaction data, whether it
+/// is read from calldata or from blobs.
+///
+/// Chunks of data are [pushed](Self::push) as they arrive and complete frames are pulled out
+/// with [Self::next_frame]. Only the bytes of a single, incomplete frame are buffered between
+/// chunks, so memory use is bounded by the chunk size plus [MAX_FRAME_LEN].
+///
+/// As with [Frame::parse_frames], the first error invalidates the rest of the data. Once an
+/// error is returned, the decoder discards all further input and [Self::finish] returns it.
+#[derive(Debug, Clone, Default)]
+pub struct FrameDecoder {
+    /// Buffered bytes that have not been decoded yet.
+    buffer: Vec<u8>,
+    /// The offset of the first buffered byte in the transaction data.
+    offset: usize,
+    /// The number of frames decoded so far.
+    decoded: usize,
+    /// The error the decoder failed with, after which it discards further input.
+    error: Option<FrameDecoderError>,
+}
+
+impl FrameDecoder {
+    /// Creates a new [FrameDecoder].
+    pub const fn new() -> Self {
+        Self { buffer: Vec::new(), offset: 0, decoded: 0, error: None }
+    }
+
+    /// Returns the offset of the next undecoded byte in the transaction data.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the number of buffered bytes that have not been decoded yet.
+    pub fn buffered(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns the number of frames decoded so far.
+    pub const fn decoded(&self) -> usize {
+        self.decoded
+    }
+
+    /// Appends the next chunk of transaction data to the decoder.
+    pub fn push(&mut self, chunk: &[u8]) {
+        if self.error.is_none() {
+            self.buffer.extend_from_slice(chunk);
+        }
+    }
+
+    /// Marks the decoder as failed, discarding any buffered data.
+    fn fail(&mut self, err: FrameDecoderError) -> Option<Result<Frame, FrameDecoderError>> {
+        self.error = Some(err);
+        self.buffer = Vec::new();
+        Some(Err(err))
+    }
+
+    /// Consumes `n` bytes from the front of the buffer.
+    fn consume(&mut self, n: usize) {
+        self.buffer.drain(..n);
+        self.offset += n;
+    }
+
+    /// Decodes the next [Frame] from the buffered data.
+    ///
+    /// ## Returns
+    /// - `Some(Ok(Frame))`: If a complete frame was decoded
+    /// - `Some(Err(_))`: If the data is invalid
+    /// - `None`: If more data is needed, or the decoder has failed
+    pub fn next_frame(&mut self) -> Option<Result<Frame, FrameDecoderError>> {
+        if self.error.is_some() {
+            return None;
+        }
+
+        // The transaction data must start with the derivation version.
+        if self.offset == 0 {
+            let version = *self.buffer.first()?;
+            if version != DERIVATION_VERSION_0 {
+                return self.fail(FrameDecoderError::UnsupportedVersion(version));
+            }
+            self.consume(1);
+        }
+
+        if self.buffer.len() < FRAME_HEADER_LEN {
+            return None;
+        }
+
+        // Reject oversized frames from the header alone, before buffering their data.
+        let data_len = u32::from_be_bytes(
+            self.buffer[FRAME_HEADER_LEN - 4..FRAME_HEADER_LEN].try_into().ok()?,
+        ) as usize;
+        if data_len > MAX_FRAME_LEN {
+            return self.fail(FrameDecoderError::Frame {
+                offset: self.offset,
+                error: FrameDecodingError::DataTooLarge(data_len),
+            });
+        }
+
+        let frame_len = FRAME_HEADER_LEN + data_len + 1;
+        if self.buffer.len() < frame_len {
+            return None;
+        }
+
+        match Frame::decode(&self.buffer[..frame_len]) {
+            Ok((_, frame)) => {
+                self.consume(frame_len);
+                self.decoded += 1;
+                Some(Ok(frame))
+            }
+            Err(error) => self.fail(FrameDecoderError::Frame { offset: self.offset, error }),
+        }
+    }
+
+    /// Signals the end of the transaction data.
+    ///
+    /// ## Returns
+    /// - `Ok(())`: If all data was decoded into at least one frame
+    /// - `Err(_)`: If decoding failed, or the data ended with an incomplete frame, or held no
+    ///   frames
+    pub fn finish(&self) -> Result<(), FrameDecoderError> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+        if !self.buffer.is_empty() {
+            return Err(FrameDecoderError::IncompleteFrame {
+                offset: self.offset,
+                buffered: self.buffer.len(),
+            });
+        }
+        if self.decoded == 0 {
+            return Err(FrameDecoderError::NoFrames);
+        }
+        Ok(())
+    }
+
+    /// Resets the decoder to decode a new transaction.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl Iterator for FrameDecoder {
+    type Item = Result<Frame, FrameDecoderError>;
+
+    /// Returns the next decoded [Frame]. Returning `None` does not mean the decoder is exhausted,
+    /// more frames may be decoded after the next [FrameDecoder::push].
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_frame()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn encoded_frames(n: u16) -> (Vec<Frame>, Vec<u8>) {
+        let frames = (0..n)
+            .map(|i| Frame { id: [0xFF; 16], number: i, data: vec![0xDD; 50], is_last: i + 1 == n })
+            .collect::<Vec<_>>();
+        let mut bytes = vec![DERIVATION_VERSION_0];
+        frames.iter().for_each(|f| bytes.extend(f.encode()));
+        (frames, bytes)
+    }
+
+    #[test]
+    fn test_decode_single_push() {
+        let (frames, bytes) = encoded_frames(3);
+        let mut decoder = FrameDecoder::new();
+        decoder.push(&bytes);
+
+        let decoded = decoder.by_ref().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(decoded, frames);
+        assert_eq!(decoder.offset(), bytes.len());
+        assert_eq!(decoder.decoded(), 3);
+        assert_eq!(decoder.finish(), Ok(()));
+    }
+
+    #[test]
+    fn test_decode_across_chunk_boundaries() {
+        let (frames, bytes) = encoded_frames(5);
+
+        for chunk_size in [1, 7, 22, 23, 73, 500] {
+            let mut decoder = FrameDecoder::new();
+            let mut decoded = Vec::new();
+            for chunk in bytes.chunks(chunk_size) {
+                decoder.push(chunk);
+                decoded.extend(decoder.by_ref().map(Result::unwrap));
+                // Never more than a single frame is buffered.
+                assert!(decoder.buffered() < 74 + chunk_size);
+            }
+            assert_eq!(decoded, frames);
+            assert_eq!(decoder.finish(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_decode_matches_parse_frames() {
+        let (_, bytes) = encoded_frames(4);
+        let mut decoder = FrameDecoder::new();
+        decoder.push(&bytes);
+        let decoded = decoder.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(decoded, Frame::parse_frames(&bytes).unwrap());
+    }
+
+    #[test]
+    fn test_decode_unsupported_version() {
+        let mut decoder = FrameDecoder::new();
+        decoder.push(&[0x01, 0x02]);
+        assert_eq!(decoder.next_frame(), Some(Err(FrameDecoderError::UnsupportedVersion(1))));
+        assert_eq!(decoder.next_frame(), None);
+        assert_eq!(decoder.buffered(), 0);
+    }
+
+    #[test]
+    fn test_decode_data_too_large_from_header() {
+        let (_, mut bytes) = encoded_frames(2);
+        // Corrupt the data length of the second frame.
+        let second = 1 + 73;
+        bytes[second + 18..second + 22].copy_from_slice(&(MAX_FRAME_LEN as u32 + 1).to_be_bytes());
+
+        let mut decoder = FrameDecoder::new();
+        decoder.push(&bytes[..second + FRAME_HEADER_LEN]);
+        assert!(decoder.next_frame().unwrap().is_ok());
+        assert_eq!(
+            decoder.next_frame(),
+            Some(Err(FrameDecoderError::Frame {
+                offset: second,
+                error: FrameDecodingError::DataTooLarge(MAX_FRAME_LEN + 1)
+            }))
+        );
+
+        // Further input is discarded.
+        decoder.push(&bytes[second + FRAME_HEADER_LEN..]);
+        assert_eq!(decoder.buffered(), 0);
+        assert_eq!(decoder.next_frame(), None);
+    }
+
+    #[test]
+    fn test_finish_incomplete_frame() {
+        let (_, bytes) = encoded_frames(2);
+        let mut decoder = FrameDecoder::new();
+        decoder.push(&bytes[..bytes.len() - 1]);
+        assert!(decoder.next_frame().unwrap().is_ok());
+        assert_eq!(decoder.next_frame(), None);
+        assert_eq!(
+            decoder.finish(),
+            Err(FrameDecoderError::IncompleteFrame { offset: 74, buffered: 72 })
+        );
+    }
+
+    #[test]
+    fn test_finish_after_failure() {
+        let (_, mut bytes) = encoded_frames(1);
+        let mut decoder = FrameDecoder::new();
+        decoder.push(&bytes);
+        assert!(decoder.next_frame().unwrap().is_ok());
+
+        // The garbage header claims more than the max frame length of data.
+        bytes.clear();
+        bytes.resize(FRAME_HEADER_LEN, 0xEE);
+        decoder.push(&bytes);
+        let err = decoder.next_frame().unwrap().unwrap_err();
+        assert_eq!(decoder.buffered(), 0);
+        assert_eq!(decoder.finish(), Err(err));
+    }
+
+    #[test]
+    fn test_finish_no_frames() {
+        let mut decoder = FrameDecoder::new();
+        assert_eq!(decoder.finish(), Err(FrameDecoderError::NoFrames));
+
+        decoder.push(&[DERIVATION_VERSION_0]);
+        assert_eq!(decoder.next_frame(), None);
+        assert_eq!(decoder.finish(), Err(FrameDecoderError::NoFrames));
+    }
+
+    #[test]
+    fn test_reset() {
+        let (frames, bytes) = encoded_frames(1);
+        let mut decoder = FrameDecoder::new();
+        decoder.push(&[0x01]);
+        assert!(decoder.next_frame().unwrap().is_err());
+
+        decoder.reset();
+        decoder.push(&bytes);
+        assert_eq!(decoder.next_frame(), Some(Ok(frames[0].clone())));
+    }
+}
@@ -0,0 +1,186 @@
+//! Network upgrade transactions of the Ecotone hardfork.
+
+use super::utils::{creation_code, upgrade_deposit, upgrade_to_calldata};
+use crate::{info::L1_INFO_DEPOSITOR_ADDRESS, Predeploys};
+use alloy_primitives::{address, hex, Address, Bytes, TxKind};
+
+/// The Ecotone network upgrade transactions.
+///
+/// The Ecotone upgrade deploys new `L1Block` and `GasPriceOracle` implementations, points their
+/// proxies at them, enables Ecotone in the `GasPriceOracle`, and deploys the EIP-4788 beacon block
+/// roots contract. See the [specs](https://specs.optimism.io/protocol/ecotone/derivation.html#network-upgrade-automation-transactions).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Ecotone;
+
+impl Ecotone {
+    /// The deployer of the Ecotone `L1Block` implementation.
+    pub const L1_BLOCK_DEPLOYER: Address = address!("4210000000000000000000000000000000000000");
+
+    /// The deployer of the Ecotone `GasPriceOracle` implementation.
+    pub const GAS_PRICE_ORACLE_DEPLOYER: Address =
+        address!("4210000000000000000000000000000000000001");
+
+    /// The pre-signed EIP-4788 deployer of the beacon block roots contract.
+    pub const BEACON_ROOTS_DEPLOYER: Address = address!("0B799C86a49DEeb90402691F1041aa3AF2d3C875");
+
+    /// The creation code of the EIP-4788 beacon block roots contract.
+    pub const BEACON_ROOTS_DEPLOYMENT_CODE: [u8; 106] = hex!("60618060095f395ff33373fffffffffffffffffffffffffffffffffffffffe14604d57602036146024575f5ffd5b5f35801560495762001fff810690815414603c575f5ffd5b62001fff01545f5260205ff35b5f5ffd5b62001fff42064281555f359062001fff015500");
+
+    /// The function selector of `setEcotone()` on the `GasPriceOracle`.
+    pub const SET_ECOTONE_SELECTOR: [u8; 4] = hex!("22b90ab3");
+
+    /// Returns the address of the Ecotone `L1Block` implementation.
+    pub fn l1_block_implementation() -> Address {
+        Self::L1_BLOCK_DEPLOYER.create(0)
+    }
+
+    /// Returns the address of the Ecotone `GasPriceOracle` implementation.
+    pub fn gas_price_oracle_implementation() -> Address {
+        Self::GAS_PRICE_ORACLE_DEPLOYER.create(0)
+    }
+
+    /// Returns the creation code of the Ecotone `L1Block` implementation.
+    pub fn l1_block_deployment_code() -> Bytes {
+        creation_code(include_str!("bytecode/l1_block_ecotone.hex"))
+    }
+
+    /// Returns the creation code of the Ecotone `GasPriceOracle` implementation.
+    pub fn gas_price_oracle_deployment_code() -> Bytes {
+        creation_code(include_str!("bytecode/gpo_ecotone.hex"))
+    }
+
+    /// Returns the transaction deploying the Ecotone `L1Block` implementation.
+    pub fn deploy_l1_block() -> Bytes {
+        upgrade_deposit(
+            "Ecotone: L1 Block Deployment",
+            Self::L1_BLOCK_DEPLOYER,
+            TxKind::Create,
+            375_000,
+            Self::l1_block_deployment_code(),
+        )
+    }
+
+    /// Returns the transaction deploying the Ecotone `GasPriceOracle` implementation.
+    pub fn deploy_gas_price_oracle() -> Bytes {
+        upgrade_deposit(
+            "Ecotone: Gas Price Oracle Deployment",
+            Self::GAS_PRICE_ORACLE_DEPLOYER,
+            TxKind::Create,
+            1_000_000,
+            Self::gas_price_oracle_deployment_code(),
+        )
+    }
+
+    /// Returns the transaction pointing the `L1Block` proxy at the Ecotone implementation.
+    pub fn update_l1_block_proxy() -> Bytes {
+        upgrade_deposit(
+            "Ecotone: L1 Block Proxy Update",
+            Address::ZERO,
+            TxKind::Call(Predeploys::L1_BLOCK),
+            50_000,
+            upgrade_to_calldata(Self::l1_block_implementation()),
+        )
+    }
+
+    /// Returns the transaction pointing the `GasPriceOracle` proxy at the Ecotone implementation.
+    pub fn update_gas_price_oracle_proxy() -> Bytes {
+        upgrade_deposit(
+            "Ecotone: Gas Price Oracle Proxy Update",
+            Address::ZERO,
+            TxKind::Call(Predeploys::GAS_PRICE_ORACLE),
+            50_000,
+            upgrade_to_calldata(Self::gas_price_oracle_implementation()),
+        )
+    }
+
+    /// Returns the transaction enabling Ecotone in the `GasPriceOracle`.
+    pub fn enable_ecotone() -> Bytes {
+        upgrade_deposit(
+            "Ecotone: Gas Price Oracle Set Ecotone",
+            L1_INFO_DEPOSITOR_ADDRESS,
+            TxKind::Call(Predeploys::GAS_PRICE_ORACLE),
+            80_000,
+            Bytes::from_static(&Self::SET_ECOTONE_SELECTOR),
+        )
+    }
+
+    /// Returns the transaction deploying the EIP-4788 beacon block roots contract.
+    pub fn deploy_beacon_roots() -> Bytes {
+        upgrade_deposit(
+            "Ecotone: beacon block roots contract deployment",
+            Self::BEACON_ROOTS_DEPLOYER,
+            TxKind::Create,
+            250_000,
+            Bytes::from_static(&Self::BEACON_ROOTS_DEPLOYMENT_CODE),
+        )
+    }
+
+    /// Returns the Ecotone network upgrade transactions, in the order they are included in the
+    /// activation block.
+    pub fn txs() -> impl Iterator<Item = Bytes> {
+        [
+            Self::deploy_l1_block(),
+            Self::deploy_gas_price_oracle(),
+            Self::update_l1_block_proxy(),
+            Self::update_gas_price_oracle_proxy(),
+            Self::enable_ecotone(),
+            Self::deploy_beacon_roots(),
+        ]
+        .into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hardforks::utils::fixture_txs;
+    use alloc::vec::Vec;
+    use alloy_eips::{eip2718::Decodable2718, eip4788::BEACON_ROOTS_ADDRESS};
+    use alloy_primitives::{b256, keccak256};
+    use op_alloy_consensus::TxDeposit;
+
+    #[test]
+    fn test_implementation_addresses() {
+        assert_eq!(
+            Ecotone::l1_block_implementation(),
+            address!("07dbe8500fc591d1852B76feE44d5a05e13097Ff")
+        );
+        assert_eq!(
+            Ecotone::gas_price_oracle_implementation(),
+            address!("b528D11cC114E026F138fE568744c6D45ce6Da7A")
+        );
+        assert_eq!(Ecotone::BEACON_ROOTS_DEPLOYER.create(0), BEACON_ROOTS_ADDRESS);
+    }
+
+    #[test]
+    fn test_set_ecotone_selector() {
+        assert_eq!(keccak256("setEcotone()")[..4], Ecotone::SET_ECOTONE_SELECTOR);
+    }
+
+    #[test]
+    fn test_source_hashes() {
+        let source_hashes = Ecotone::txs()
+            .map(|tx| TxDeposit::decode_2718(&mut tx.as_ref()).unwrap().source_hash)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            source_hashes,
+            [
+                b256!("877a6077205782ea15a6dc8699fa5ebcec5e0f4389f09cb8eda09488231346f8"),
+                b256!("a312b4510adf943510f05fcc8f15f86995a5066bd83ce11384688ae20e6ecf42"),
+                b256!("18acb38c5ff1c238a7460ebc1b421fa49ec4874bdf1e0a530d234104e5e67dbc"),
+                b256!("ee4f9385eceef498af0be7ec5862229f426dec41c8d42397c7257a5117d9230a"),
+                b256!("0c1cb38e99dbc9cbfab3bb80863380b0905290b37eb3d6ab18dc01c1f3e75f93"),
+                b256!("69b763c48478b9dc2f65ada09b3d92133ec592ea715ec65ad6e7f3dc519dc00c"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_encoded_txs() {
+        // The upgrade transactions of the Ecotone activation block, as generated by op-node.
+        assert_eq!(
+            Ecotone::txs().collect::<Vec<_>>(),
+            fixture_txs(include_str!("testdata/ecotone.hex"))
+        );
+    }
+}
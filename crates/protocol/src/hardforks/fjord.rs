@@ -0,0 +1,122 @@
+//! Network upgrade transactions of the Fjord hardfork.
+
+use super::utils::{creation_code, upgrade_deposit, upgrade_to_calldata};
+use crate::{info::L1_INFO_DEPOSITOR_ADDRESS, Predeploys};
+use alloy_primitives::{address, hex, Address, Bytes, TxKind};
+
+/// The Fjord network upgrade transactions.
+///
+/// The Fjord upgrade deploys a new `GasPriceOracle` implementation, points its proxy at it, and
+/// enables Fjord in the `GasPriceOracle`. See the [specs](https://specs.optimism.io/protocol/fjord/derivation.html#network-upgrade-automation-transactions).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Fjord;
+
+impl Fjord {
+    /// The deployer of the Fjord `GasPriceOracle` implementation.
+    pub const GAS_PRICE_ORACLE_DEPLOYER: Address =
+        address!("4210000000000000000000000000000000000002");
+
+    /// The function selector of `setFjord()` on the `GasPriceOracle`.
+    pub const SET_FJORD_SELECTOR: [u8; 4] = hex!("8e98b106");
+
+    /// Returns the address of the Fjord `GasPriceOracle` implementation.
+    pub fn gas_price_oracle_implementation() -> Address {
+        Self::GAS_PRICE_ORACLE_DEPLOYER.create(0)
+    }
+
+    /// Returns the creation code of the Fjord `GasPriceOracle` implementation.
+    pub fn gas_price_oracle_deployment_code() -> Bytes {
+        creation_code(include_str!("bytecode/gpo_fjord.hex"))
+    }
+
+    /// Returns the transaction deploying the Fjord `GasPriceOracle` implementation.
+    pub fn deploy_gas_price_oracle() -> Bytes {
+        upgrade_deposit(
+            "Fjord: Gas Price Oracle Deployment",
+            Self::GAS_PRICE_ORACLE_DEPLOYER,
+            TxKind::Create,
+            1_450_000,
+            Self::gas_price_oracle_deployment_code(),
+        )
+    }
+
+    /// Returns the transaction pointing the `GasPriceOracle` proxy at the Fjord implementation.
+    pub fn update_gas_price_oracle_proxy() -> Bytes {
+        upgrade_deposit(
+            "Fjord: Gas Price Oracle Proxy Update",
+            Address::ZERO,
+            TxKind::Call(Predeploys::GAS_PRICE_ORACLE),
+            50_000,
+            upgrade_to_calldata(Self::gas_price_oracle_implementation()),
+        )
+    }
+
+    /// Returns the transaction enabling Fjord in the `GasPriceOracle`.
+    pub fn enable_fjord() -> Bytes {
+        upgrade_deposit(
+            "Fjord: Gas Price Oracle Set Fjord",
+            L1_INFO_DEPOSITOR_ADDRESS,
+            TxKind::Call(Predeploys::GAS_PRICE_ORACLE),
+            90_000,
+            Bytes::from_static(&Self::SET_FJORD_SELECTOR),
+        )
+    }
+
+    /// Returns the Fjord network upgrade transactions, in the order they are included in the
+    /// activation block.
+    pub fn txs() -> impl Iterator<Item = Bytes> {
+        [
+            Self::deploy_gas_price_oracle(),
+            Self::update_gas_price_oracle_proxy(),
+            Self::enable_fjord(),
+        ]
+        .into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hardforks::utils::fixture_txs;
+    use alloc::vec::Vec;
+    use alloy_eips::eip2718::Decodable2718;
+    use alloy_primitives::{b256, keccak256};
+    use op_alloy_consensus::TxDeposit;
+
+    #[test]
+    fn test_implementation_address() {
+        assert_eq!(
+            Fjord::gas_price_oracle_implementation(),
+            address!("a919894851548179A0750865e7974DA599C0Fac7")
+        );
+    }
+
+    #[test]
+    fn test_set_fjord_selector() {
+        assert_eq!(keccak256("setFjord()")[..4], Fjord::SET_FJORD_SELECTOR);
+    }
+
+    #[test]
+    fn test_source_hashes() {
+        let source_hashes = Fjord::txs()
+            .map(|tx| TxDeposit::decode_2718(&mut tx.as_ref()).unwrap().source_hash)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            source_hashes,
+            [
+                b256!("86122c533fdcb89b16d8713174625e44578a89751d96c098ec19ab40a51a8ea3"),
+                b256!("1e6bb0c28bfab3dc9b36ffb0f721f00d6937f33577606325692db0965a7d58c6"),
+                b256!("bac7bb0d5961cad209a345408b0280a0d4686b1b20665e1b0f9cdafd73b19b6b"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_encoded_txs() {
+        // The upgrade transactions of the Fjord activation block, as generated by op-node.
+        assert_eq!(
+            Fjord::txs().collect::<Vec<_>>(),
+            fixture_txs(include_str!("testdata/fjord.hex"))
+        );
+    }
+}
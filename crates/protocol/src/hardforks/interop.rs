@@ -0,0 +1,148 @@
+//! Network upgrade transactions of the Interop hardfork.
+
+use super::utils::{creation_code, upgrade_deposit, upgrade_to_calldata};
+use crate::Predeploys;
+use alloy_primitives::{address, Address, Bytes, TxKind};
+
+/// The Interop network upgrade transactions.
+///
+/// The Interop upgrade deploys the `CrossL2Inbox` and `L2ToL2CrossDomainMessenger`
+/// implementations, and points their proxies at them.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Interop;
+
+impl Interop {
+    /// The deployer of the `CrossL2Inbox` implementation.
+    pub const CROSS_L2_INBOX_DEPLOYER: Address =
+        address!("4220000000000000000000000000000000000000");
+
+    /// The deployer of the `L2ToL2CrossDomainMessenger` implementation.
+    pub const L2_TO_L2_MESSENGER_DEPLOYER: Address =
+        address!("4220000000000000000000000000000000000001");
+
+    /// Returns the address of the `CrossL2Inbox` implementation.
+    pub fn cross_l2_inbox_implementation() -> Address {
+        Self::CROSS_L2_INBOX_DEPLOYER.create(0)
+    }
+
+    /// Returns the address of the `L2ToL2CrossDomainMessenger` implementation.
+    pub fn l2_to_l2_messenger_implementation() -> Address {
+        Self::L2_TO_L2_MESSENGER_DEPLOYER.create(0)
+    }
+
+    /// Returns the creation code of the `CrossL2Inbox` implementation.
+    pub fn cross_l2_inbox_deployment_code() -> Bytes {
+        creation_code(include_str!("bytecode/crossl2inbox_interop.hex"))
+    }
+
+    /// Returns the creation code of the `L2ToL2CrossDomainMessenger` implementation.
+    pub fn l2_to_l2_messenger_deployment_code() -> Bytes {
+        creation_code(include_str!("bytecode/l2tol2_xdm_interop.hex"))
+    }
+
+    /// Returns the transaction deploying the `CrossL2Inbox` implementation.
+    pub fn deploy_cross_l2_inbox() -> Bytes {
+        upgrade_deposit(
+            "Interop: CrossL2Inbox Deployment",
+            Self::CROSS_L2_INBOX_DEPLOYER,
+            TxKind::Create,
+            420_000,
+            Self::cross_l2_inbox_deployment_code(),
+        )
+    }
+
+    /// Returns the transaction deploying the `L2ToL2CrossDomainMessenger` implementation.
+    pub fn deploy_l2_to_l2_messenger() -> Bytes {
+        upgrade_deposit(
+            "Interop: L2ToL2CrossDomainMessenger Deployment",
+            Self::L2_TO_L2_MESSENGER_DEPLOYER,
+            TxKind::Create,
+            1_100_000,
+            Self::l2_to_l2_messenger_deployment_code(),
+        )
+    }
+
+    /// Returns the transaction pointing the `CrossL2Inbox` proxy at its implementation.
+    pub fn update_cross_l2_inbox_proxy() -> Bytes {
+        upgrade_deposit(
+            "Interop: CrossL2Inbox Proxy Update",
+            Address::ZERO,
+            TxKind::Call(Predeploys::CROSS_L2_INBOX),
+            50_000,
+            upgrade_to_calldata(Self::cross_l2_inbox_implementation()),
+        )
+    }
+
+    /// Returns the transaction pointing the `L2ToL2CrossDomainMessenger` proxy at its
+    /// implementation.
+    pub fn update_l2_to_l2_messenger_proxy() -> Bytes {
+        upgrade_deposit(
+            "Interop: L2ToL2CrossDomainMessenger Proxy Update",
+            Address::ZERO,
+            TxKind::Call(Predeploys::L2_TO_L2_CROSS_DOMAIN_MESSENGER),
+            50_000,
+            upgrade_to_calldata(Self::l2_to_l2_messenger_implementation()),
+        )
+    }
+
+    /// Returns the Interop network upgrade transactions, in the order they are included in the
+    /// activation block.
+    ///
+    /// Each implementation is deployed right before its proxy update.
+    pub fn txs() -> impl Iterator<Item = Bytes> {
+        [
+            Self::deploy_cross_l2_inbox(),
+            Self::update_cross_l2_inbox_proxy(),
+            Self::deploy_l2_to_l2_messenger(),
+            Self::update_l2_to_l2_messenger_proxy(),
+        ]
+        .into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hardforks::utils::fixture_txs;
+    use alloc::vec::Vec;
+    use alloy_eips::eip2718::Decodable2718;
+    use alloy_primitives::b256;
+    use op_alloy_consensus::TxDeposit;
+
+    #[test]
+    fn test_implementation_addresses() {
+        assert_eq!(
+            Interop::cross_l2_inbox_implementation(),
+            address!("691300f512e48B463C2617b34Eef1A9f82EE7dBf")
+        );
+        assert_eq!(
+            Interop::l2_to_l2_messenger_implementation(),
+            address!("0D0eDd0ebd0e94d218670a8De867Eb5C4d37cadD")
+        );
+    }
+
+    #[test]
+    fn test_source_hashes() {
+        let source_hashes = Interop::txs()
+            .map(|tx| TxDeposit::decode_2718(&mut tx.as_ref()).unwrap().source_hash)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            source_hashes,
+            [
+                b256!("6e5e214f73143df8fe6f6054a3ed7eb472d373376458a9c8aecdf23475beb616"),
+                b256!("88c6b48354c367125a59792a93a7b60ad7cd66e516157dbba16558c68a46d3cb"),
+                b256!("f5484697c7a9a791db32a3bf0763bf2ba686c77ae7d4c0a5ee8c222a92a8dcc2"),
+                b256!("e54b4d06bbcc857f41ae00e89d820339ac5ce0034aac722c817b2873e03a7e68"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_encoded_txs() {
+        // The upgrade transactions of the Interop activation block, as generated by op-node.
+        assert_eq!(
+            Interop::txs().collect::<Vec<_>>(),
+            fixture_txs(include_str!("testdata/interop.hex"))
+        );
+    }
+}
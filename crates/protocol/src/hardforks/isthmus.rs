@@ -0,0 +1,231 @@
+//! Network upgrade transactions of the Isthmus hardfork.
+
+use super::utils::{creation_code, upgrade_deposit, upgrade_to_calldata};
+use crate::{info::L1_INFO_DEPOSITOR_ADDRESS, Predeploys};
+use alloy_primitives::{address, hex, Address, Bytes, TxKind};
+
+/// The Isthmus network upgrade transactions.
+///
+/// The Isthmus upgrade deploys new `L1Block`, `GasPriceOracle` and `OperatorFeeVault`
+/// implementations, points their proxies at them, enables Isthmus in the `GasPriceOracle`, and
+/// deploys the EIP-2935 block hash history contract.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Isthmus;
+
+impl Isthmus {
+    /// The deployer of the Isthmus `L1Block` implementation.
+    pub const L1_BLOCK_DEPLOYER: Address = address!("4210000000000000000000000000000000000003");
+
+    /// The deployer of the Isthmus `GasPriceOracle` implementation.
+    pub const GAS_PRICE_ORACLE_DEPLOYER: Address =
+        address!("4210000000000000000000000000000000000004");
+
+    /// The deployer of the `OperatorFeeVault` implementation.
+    pub const OPERATOR_FEE_VAULT_DEPLOYER: Address =
+        address!("4210000000000000000000000000000000000005");
+
+    /// The pre-signed EIP-2935 deployer of the block hash history contract.
+    pub const BLOCK_HASH_HISTORY_DEPLOYER: Address =
+        address!("3462413Af4609098e1E27A490f554f260213D685");
+
+    /// The creation code of the EIP-2935 block hash history contract.
+    pub const BLOCK_HASH_HISTORY_DEPLOYMENT_CODE: [u8; 92] = hex!("60538060095f395ff33373fffffffffffffffffffffffffffffffffffffffe14604657602036036042575f35600143038111604257611fff81430311604257611fff9006545f5260205ff35b5f5ffd5b5f35611fff60014303065500");
+
+    /// The function selector of `setIsthmus()` on the `GasPriceOracle`.
+    pub const SET_ISTHMUS_SELECTOR: [u8; 4] = hex!("291b0383");
+
+    /// Returns the address of the Isthmus `L1Block` implementation.
+    pub fn l1_block_implementation() -> Address {
+        Self::L1_BLOCK_DEPLOYER.create(0)
+    }
+
+    /// Returns the address of the Isthmus `GasPriceOracle` implementation.
+    pub fn gas_price_oracle_implementation() -> Address {
+        Self::GAS_PRICE_ORACLE_DEPLOYER.create(0)
+    }
+
+    /// Returns the address of the `OperatorFeeVault` implementation.
+    pub fn operator_fee_vault_implementation() -> Address {
+        Self::OPERATOR_FEE_VAULT_DEPLOYER.create(0)
+    }
+
+    /// Returns the creation code of the Isthmus `L1Block` implementation.
+    pub fn l1_block_deployment_code() -> Bytes {
+        creation_code(include_str!("bytecode/l1_block_isthmus.hex"))
+    }
+
+    /// Returns the creation code of the Isthmus `GasPriceOracle` implementation.
+    pub fn gas_price_oracle_deployment_code() -> Bytes {
+        creation_code(include_str!("bytecode/gpo_isthmus.hex"))
+    }
+
+    /// Returns the creation code of the `OperatorFeeVault` implementation.
+    pub fn operator_fee_vault_deployment_code() -> Bytes {
+        creation_code(include_str!("bytecode/ofv_isthmus.hex"))
+    }
+
+    /// Returns the transaction deploying the Isthmus `L1Block` implementation.
+    pub fn deploy_l1_block() -> Bytes {
+        upgrade_deposit(
+            "Isthmus: L1 Block Deployment",
+            Self::L1_BLOCK_DEPLOYER,
+            TxKind::Create,
+            425_000,
+            Self::l1_block_deployment_code(),
+        )
+    }
+
+    /// Returns the transaction deploying the Isthmus `GasPriceOracle` implementation.
+    pub fn deploy_gas_price_oracle() -> Bytes {
+        upgrade_deposit(
+            "Isthmus: Gas Price Oracle Deployment",
+            Self::GAS_PRICE_ORACLE_DEPLOYER,
+            TxKind::Create,
+            1_625_000,
+            Self::gas_price_oracle_deployment_code(),
+        )
+    }
+
+    /// Returns the transaction deploying the `OperatorFeeVault` implementation.
+    pub fn deploy_operator_fee_vault() -> Bytes {
+        upgrade_deposit(
+            "Isthmus: Operator Fee Vault Deployment",
+            Self::OPERATOR_FEE_VAULT_DEPLOYER,
+            TxKind::Create,
+            500_000,
+            Self::operator_fee_vault_deployment_code(),
+        )
+    }
+
+    /// Returns the transaction pointing the `L1Block` proxy at the Isthmus implementation.
+    pub fn update_l1_block_proxy() -> Bytes {
+        upgrade_deposit(
+            "Isthmus: L1 Block Proxy Update",
+            Address::ZERO,
+            TxKind::Call(Predeploys::L1_BLOCK),
+            50_000,
+            upgrade_to_calldata(Self::l1_block_implementation()),
+        )
+    }
+
+    /// Returns the transaction pointing the `GasPriceOracle` proxy at the Isthmus implementation.
+    pub fn update_gas_price_oracle_proxy() -> Bytes {
+        upgrade_deposit(
+            "Isthmus: Gas Price Oracle Proxy Update",
+            Address::ZERO,
+            TxKind::Call(Predeploys::GAS_PRICE_ORACLE),
+            50_000,
+            upgrade_to_calldata(Self::gas_price_oracle_implementation()),
+        )
+    }
+
+    /// Returns the transaction pointing the `OperatorFeeVault` proxy at its implementation.
+    pub fn update_operator_fee_vault_proxy() -> Bytes {
+        upgrade_deposit(
+            "Isthmus: Operator Fee Vault Proxy Update",
+            Address::ZERO,
+            TxKind::Call(Predeploys::OPERATOR_FEE_VAULT),
+            50_000,
+            upgrade_to_calldata(Self::operator_fee_vault_implementation()),
+        )
+    }
+
+    /// Returns the transaction enabling Isthmus in the `GasPriceOracle`.
+    pub fn enable_isthmus() -> Bytes {
+        upgrade_deposit(
+            "Isthmus: Gas Price Oracle Set Isthmus",
+            L1_INFO_DEPOSITOR_ADDRESS,
+            TxKind::Call(Predeploys::GAS_PRICE_ORACLE),
+            90_000,
+            Bytes::from_static(&Self::SET_ISTHMUS_SELECTOR),
+        )
+    }
+
+    /// Returns the transaction deploying the EIP-2935 block hash history contract.
+    pub fn deploy_block_hash_history() -> Bytes {
+        upgrade_deposit(
+            "Isthmus: EIP-2935 Contract Deployment",
+            Self::BLOCK_HASH_HISTORY_DEPLOYER,
+            TxKind::Create,
+            250_000,
+            Bytes::from_static(&Self::BLOCK_HASH_HISTORY_DEPLOYMENT_CODE),
+        )
+    }
+
+    /// Returns the Isthmus network upgrade transactions, in the order they are included in the
+    /// activation block.
+    pub fn txs() -> impl Iterator<Item = Bytes> {
+        [
+            Self::deploy_l1_block(),
+            Self::deploy_gas_price_oracle(),
+            Self::deploy_operator_fee_vault(),
+            Self::update_l1_block_proxy(),
+            Self::update_gas_price_oracle_proxy(),
+            Self::update_operator_fee_vault_proxy(),
+            Self::enable_isthmus(),
+            Self::deploy_block_hash_history(),
+        ]
+        .into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hardforks::utils::fixture_txs;
+    use alloc::vec::Vec;
+    use alloy_eips::{eip2718::Decodable2718, eip2935::HISTORY_STORAGE_ADDRESS};
+    use alloy_primitives::{b256, keccak256};
+    use op_alloy_consensus::TxDeposit;
+
+    #[test]
+    fn test_implementation_addresses() {
+        assert_eq!(
+            Isthmus::l1_block_implementation(),
+            address!("Ff256497D61dcd71a9e9Ff43967C13fdE1F72D12")
+        );
+        assert_eq!(
+            Isthmus::gas_price_oracle_implementation(),
+            address!("93e57A196454CB919193fa9946f14943cf733845")
+        );
+        assert_eq!(
+            Isthmus::operator_fee_vault_implementation(),
+            address!("4fa2Be8cd41504037F1838BcE3bCC93bC68Ff537")
+        );
+        assert_eq!(Isthmus::BLOCK_HASH_HISTORY_DEPLOYER.create(0), HISTORY_STORAGE_ADDRESS);
+    }
+
+    #[test]
+    fn test_set_isthmus_selector() {
+        assert_eq!(keccak256("setIsthmus()")[..4], Isthmus::SET_ISTHMUS_SELECTOR);
+    }
+
+    #[test]
+    fn test_source_hashes() {
+        let source_hashes = Isthmus::txs()
+            .map(|tx| TxDeposit::decode_2718(&mut tx.as_ref()).unwrap().source_hash)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            source_hashes,
+            [
+                b256!("3b2d0821ca2411ad5cd3595804d1213d15737188ae4cbd58aa19c821a6c211bf"),
+                b256!("fc70b48424763fa3fab9844253b4f8d508f91eb1f7cb11a247c9baec0afb8035"),
+                b256!("107a570d3db75e6110817eb024f09f3172657e920634111ce9875d08a16daa96"),
+                b256!("ebe8b5cb10ca47e0d8bda8f5355f2d66711a54ddeb0ef1d30e29418c9bf17a0e"),
+                b256!("ecf2d9161d26c54eda6b7bfdd9142719b1e1199a6e5641468d1bf705bc531ab0"),
+                b256!("ad74e1adb877ccbe176b8fa1cc559388a16e090ddbe8b512f5b37d07d887a927"),
+                b256!("3ddf4b1302548dd92939826e970f260ba36167f4c25f18390a5e8b194b295319"),
+                b256!("bfb734dae514c5974ddf803e54c1bc43d5cdb4a48ae27e1d9b875a5a150b553a"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_encoded_txs() {
+        // The upgrade transactions of the Isthmus activation block, as generated by op-node.
+        assert_eq!(
+            Isthmus::txs().collect::<Vec<_>>(),
+            fixture_txs(include_str!("testdata/isthmus.hex"))
+        );
+    }
+}
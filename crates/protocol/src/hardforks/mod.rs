@@ -0,0 +1,22 @@
+//! Network upgrade transactions.
+//!
+//! At the activation block of some hardforks, deposit transactions are injected after the L1 info
+//! transaction to deploy and upgrade predeploys. See the
+//! [specs](https://specs.optimism.io/protocol/derivation.html#network-upgrade-automation-transactions).
+//!
+//! The creation code of the deployed predeploy implementations is embedded in the crate, as
+//! compiled for the upgrade in the OP Stack monorepo.
+
+mod utils;
+
+mod ecotone;
+pub use ecotone::Ecotone;
+
+mod fjord;
+pub use fjord::Fjord;
+
+mod isthmus;
+pub use isthmus::Isthmus;
+
+mod interop;
+pub use interop::Interop;
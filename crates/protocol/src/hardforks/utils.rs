@@ -0,0 +1,69 @@
+//! Utilities for building network upgrade transactions.
+
+use alloy_eips::eip2718::Encodable2718;
+use alloy_primitives::{hex, Address, Bytes, TxKind, U256};
+use op_alloy_consensus::{TxDeposit, UpgradeDepositSource};
+
+/// The function selector of `upgradeTo(address)` on the predeploy proxies.
+pub(crate) const UPGRADE_TO_SELECTOR: [u8; 4] = hex!("3659cfe6");
+
+/// Returns the calldata of a proxy `upgradeTo(address)` call.
+pub(crate) fn upgrade_to_calldata(implementation: Address) -> Bytes {
+    let mut calldata = [0u8; 4 + 32];
+    calldata[..4].copy_from_slice(&UPGRADE_TO_SELECTOR);
+    calldata[16..].copy_from_slice(implementation.as_slice());
+    Bytes::copy_from_slice(&calldata)
+}
+
+/// Decodes the embedded, hex encoded creation code of a predeploy implementation.
+pub(crate) fn creation_code(code: &str) -> Bytes {
+    hex::decode(code.trim()).expect("embedded creation code is valid hex").into()
+}
+
+/// Decodes the hex encoded network upgrade transactions of a fixture, one per line.
+#[cfg(test)]
+pub(crate) fn fixture_txs(fixture: &str) -> alloc::vec::Vec<Bytes> {
+    fixture.lines().map(|tx| hex::decode(tx).unwrap().into()).collect()
+}
+
+/// Returns the EIP-2718 encoding of a network upgrade deposit transaction, with its source hash
+/// derived from the `intent`.
+pub(crate) fn upgrade_deposit(
+    intent: &str,
+    from: Address,
+    to: TxKind,
+    gas_limit: u64,
+    input: Bytes,
+) -> Bytes {
+    let tx = TxDeposit {
+        source_hash: UpgradeDepositSource { intent: intent.into() }.source_hash(),
+        from,
+        to,
+        mint: None,
+        value: U256::ZERO,
+        gas_limit,
+        is_system_transaction: false,
+        input,
+    };
+    tx.encoded_2718().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, keccak256};
+
+    #[test]
+    fn test_upgrade_to_selector() {
+        assert_eq!(keccak256("upgradeTo(address)")[..4], UPGRADE_TO_SELECTOR);
+    }
+
+    #[test]
+    fn test_upgrade_to_calldata() {
+        let calldata = upgrade_to_calldata(address!("07dbe8500fc591d1852B76feE44d5a05e13097Ff"));
+        assert_eq!(
+            calldata.as_ref(),
+            hex!("3659cfe600000000000000000000000007dbe8500fc591d1852b76fee44d5a05e13097ff")
+        );
+    }
+}
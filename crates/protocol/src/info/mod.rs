@@ -2,6 +2,7 @@
 
 mod variant;
 pub use variant::L1BlockInfoTx;
+pub(crate) use variant::L1_INFO_DEPOSITOR_ADDRESS;
 
 mod isthmus;
 pub use isthmus::L1BlockInfoIsthmus;
@@ -4,7 +4,7 @@
 use alloy_consensus::Header;
 use alloy_eips::{eip7840::BlobParams, BlockNumHash};
 use alloy_primitives::{address, Address, Bytes, Sealable, Sealed, TxKind, B256, U256};
-use maili_genesis::{RollupConfig, SystemConfig};
+use maili_genesis::{OpHardfork, RollupConfig, SystemConfig};
 use op_alloy_consensus::{DepositSourceDomain, L1InfoDepositSource, TxDeposit};
 
 use crate::{
@@ -53,7 +53,7 @@ impl L1BlockInfoTx {
         // upgrade transactions being placed after the L1 info transaction. Because of this,
         // for the first block of Ecotone, we send a Bedrock style L1 block info transaction
         let is_first_ecotone_block =
-            rollup_config.ecotone_time.unwrap_or_default() == l2_block_time;
+            rollup_config.is_first_fork_block(OpHardfork::Ecotone, l2_block_time);
 
         // If ecotone is *not* active or this is the first block of ecotone, use Bedrock block info.
         if !rollup_config.is_ecotone_active(l2_block_time) || is_first_ecotone_block {
@@ -85,7 +85,7 @@ impl L1BlockInfoTx {
         );
 
         if rollup_config.is_interop_active(l2_block_time)
-            && rollup_config.interop_time.unwrap_or_default() != l2_block_time
+            && !rollup_config.is_first_fork_block(OpHardfork::Interop, l2_block_time)
         {
             return Ok(Self::Interop(L1BlockInfoInterop {
                 number: l1_header.number,
@@ -101,7 +101,7 @@ impl L1BlockInfoTx {
         }
 
         if rollup_config.is_isthmus_active(l2_block_time)
-            && rollup_config.isthmus_time.unwrap_or_default() != l2_block_time
+            && !rollup_config.is_first_fork_block(OpHardfork::Isthmus, l2_block_time)
         {
             let operator_fee_scalar = system_config.operator_fee_scalar.unwrap_or_default();
             let operator_fee_constant = system_config.operator_fee_constant.unwrap_or_default();
@@ -11,9 +11,10 @@ extern crate alloc;
 
 mod batch;
 pub use batch::{
-    Batch, BatchDecodingError, BatchEncodingError, BatchReader, BatchTransaction, BatchType,
-    BatchValidationProvider, BatchValidity, BatchWithInclusionBlock, RawSpanBatch, SingleBatch,
-    SpanBatch, SpanBatchBits, SpanBatchEip1559TransactionData, SpanBatchEip2930TransactionData,
+    Batch, BatchDecodingError, BatchEncodingError, BatchReader, BatchReaderError, BatchTransaction,
+    BatchType, BatchValidationProvider, BatchValidity, BatchWithInclusionBlock, ChannelCompression,
+    RawSpanBatch, SingleBatch, SpanBatch, SpanBatchBits, SpanBatchBuilder, SpanBatchBuilderError,
+    SpanBatchEip1559TransactionData, SpanBatchEip2930TransactionData,
     SpanBatchEip7702TransactionData, SpanBatchElement, SpanBatchError,
     SpanBatchLegacyTransactionData, SpanBatchPayload, SpanBatchPrefix, SpanBatchTransactionData,
     SpanBatchTransactions, SpanDecodingError, MAX_SPAN_BATCH_ELEMENTS, SINGLE_BATCH_TYPE,
@@ -31,6 +32,11 @@ pub use frame::{
     Frame, FrameDecodingError, FrameParseError, DERIVATION_VERSION_0, FRAME_OVERHEAD, MAX_FRAME_LEN,
 };
 
+mod blob;
+pub use blob::{
+    BlobData, BlobDecodingError, BlobEncodingError, BLOB_ENCODING_VERSION, BLOB_MAX_DATA_SIZE,
+};
+
 mod compression;
 pub use compression::{
     compress_zlib, decompress_brotli, decompress_zlib, BrotliDecompressionError, BrotliLevel,
@@ -45,6 +51,9 @@ pub use compression::{
 mod iter;
 pub use iter::FrameIter;
 
+mod frame_decoder;
+pub use frame_decoder::{FrameDecoder, FrameDecoderError};
+
 mod utils;
 pub use utils::{read_tx_data, starts_with_2718_deposit, starts_with_7702_tx, to_system_config};
 
@@ -57,12 +66,26 @@ pub use channel::{
     MAX_RLP_BYTES_PER_CHANNEL,
 };
 
+mod channel_bank;
+pub use channel_bank::{
+    ChannelBank, ChannelBankError, FJORD_MAX_CHANNEL_BANK_SIZE, MAX_CHANNEL_BANK_SIZE,
+};
+
 mod deposits;
 pub use deposits::{
-    decode_deposit, DepositError, DEPOSIT_EVENT_ABI, DEPOSIT_EVENT_ABI_HASH,
+    decode_deposit, derive_deposits, DepositError, DEPOSIT_EVENT_ABI, DEPOSIT_EVENT_ABI_HASH,
     DEPOSIT_EVENT_VERSION_0,
 };
 
+mod withdrawals;
+pub use withdrawals::{
+    hash_withdrawal, sent_messages_slot, verify_withdrawal_proof, MessagePassed, WithdrawalError,
+    WithdrawalProof, WithdrawalTransaction, SENT_MESSAGES_SLOT,
+};
+
+mod attributes;
+pub use attributes::{AttributesBuilder, AttributesBuilderError};
+
 mod info;
 pub use info::{
     closing_deposit_context_tx, BlockInfoError, DecodeError, L1BlockInfoBedrock,
@@ -74,10 +97,17 @@ pub use fee::{
     calculate_tx_l1_cost_bedrock, calculate_tx_l1_cost_bedrock_empty_scalars,
     calculate_tx_l1_cost_ecotone, calculate_tx_l1_cost_fjord, calculate_tx_l1_cost_regolith,
     data_gas_bedrock, data_gas_fjord, data_gas_regolith, flz_compress_len, tx_estimated_size_fjord,
+    L1FeeBreakdown, L1FeeCalculator,
 };
 
+mod receipts;
+pub use receipts::{l1_receipt_fields, L1ReceiptFields};
+
+mod hardforks;
+pub use hardforks::{Ecotone, Fjord, Interop, Isthmus};
+
 mod predeploys;
-pub use predeploys::Predeploys;
+pub use predeploys::{Predeploy, Predeploys};
 
 #[cfg(any(test, feature = "test-utils"))]
 pub mod test_utils;
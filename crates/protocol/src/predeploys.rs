@@ -4,6 +4,34 @@
 //! See the complete set of predeploys at <https://specs.optimism.io/protocol/predeploys.html#predeploys>
 
 use alloy_primitives::{address, Address};
+use maili_genesis::OpHardfork;
+
+/// Metadata of a predeploy contract.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Predeploy {
+    /// The contract name, as listed in the specs.
+    pub name: &'static str,
+    /// The address of the predeploy.
+    pub address: Address,
+    /// The hardfork in which the predeploy was introduced.
+    ///
+    /// Predeploys that predate Bedrock are reported as introduced in [OpHardfork::Bedrock].
+    pub introduced: OpHardfork,
+    /// Whether the predeploy sits behind a proxy.
+    pub proxied: bool,
+}
+
+impl Predeploy {
+    /// Creates a new [Predeploy].
+    const fn new(
+        name: &'static str,
+        address: Address,
+        introduced: OpHardfork,
+        proxied: bool,
+    ) -> Self {
+        Self { name, address, introduced, proxied }
+    }
+}
 
 /// Container for all predeploy contract addresses
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -12,9 +40,247 @@ pub struct Predeploys;
 
 impl Predeploys {
     /// List of all predeploys.
-    pub const ALL: [Address; 1] = [Self::L2_TO_L1_MESSAGE_PASSER];
+    pub const ALL: [Address; 27] = [
+        Self::LEGACY_MESSAGE_PASSER,
+        Self::DEPLOYER_WHITELIST,
+        Self::LEGACY_ERC20_ETH,
+        Self::WETH9,
+        Self::L2_CROSS_DOMAIN_MESSENGER,
+        Self::L2_STANDARD_BRIDGE,
+        Self::SEQUENCER_FEE_VAULT,
+        Self::OPTIMISM_MINTABLE_ERC20_FACTORY,
+        Self::L1_BLOCK_NUMBER,
+        Self::GAS_PRICE_ORACLE,
+        Self::GOVERNANCE_TOKEN,
+        Self::L1_BLOCK,
+        Self::L2_TO_L1_MESSAGE_PASSER,
+        Self::L2_ERC721_BRIDGE,
+        Self::OPTIMISM_MINTABLE_ERC721_FACTORY,
+        Self::PROXY_ADMIN,
+        Self::BASE_FEE_VAULT,
+        Self::L1_FEE_VAULT,
+        Self::SCHEMA_REGISTRY,
+        Self::EAS,
+        Self::BEACON_BLOCK_ROOT,
+        Self::OPERATOR_FEE_VAULT,
+        Self::CROSS_L2_INBOX,
+        Self::L2_TO_L2_CROSS_DOMAIN_MESSENGER,
+        Self::SUPERCHAIN_WETH,
+        Self::ETH_LIQUIDITY,
+        Self::SUPERCHAIN_TOKEN_BRIDGE,
+    ];
+
+    /// Metadata of all predeploys, in the same order as [Predeploys::ALL].
+    pub const METADATA: [Predeploy; 27] = [
+        Predeploy::new(
+            "LegacyMessagePasser",
+            Self::LEGACY_MESSAGE_PASSER,
+            OpHardfork::Bedrock,
+            true,
+        ),
+        Predeploy::new("DeployerWhitelist", Self::DEPLOYER_WHITELIST, OpHardfork::Bedrock, true),
+        Predeploy::new("LegacyERC20ETH", Self::LEGACY_ERC20_ETH, OpHardfork::Bedrock, false),
+        Predeploy::new("WETH9", Self::WETH9, OpHardfork::Bedrock, false),
+        Predeploy::new(
+            "L2CrossDomainMessenger",
+            Self::L2_CROSS_DOMAIN_MESSENGER,
+            OpHardfork::Bedrock,
+            true,
+        ),
+        Predeploy::new("L2StandardBridge", Self::L2_STANDARD_BRIDGE, OpHardfork::Bedrock, true),
+        Predeploy::new("SequencerFeeVault", Self::SEQUENCER_FEE_VAULT, OpHardfork::Bedrock, true),
+        Predeploy::new(
+            "OptimismMintableERC20Factory",
+            Self::OPTIMISM_MINTABLE_ERC20_FACTORY,
+            OpHardfork::Bedrock,
+            true,
+        ),
+        Predeploy::new("L1BlockNumber", Self::L1_BLOCK_NUMBER, OpHardfork::Bedrock, true),
+        Predeploy::new("GasPriceOracle", Self::GAS_PRICE_ORACLE, OpHardfork::Bedrock, true),
+        Predeploy::new("GovernanceToken", Self::GOVERNANCE_TOKEN, OpHardfork::Bedrock, false),
+        Predeploy::new("L1Block", Self::L1_BLOCK, OpHardfork::Bedrock, true),
+        Predeploy::new(
+            "L2ToL1MessagePasser",
+            Self::L2_TO_L1_MESSAGE_PASSER,
+            OpHardfork::Bedrock,
+            true,
+        ),
+        Predeploy::new("L2ERC721Bridge", Self::L2_ERC721_BRIDGE, OpHardfork::Bedrock, true),
+        Predeploy::new(
+            "OptimismMintableERC721Factory",
+            Self::OPTIMISM_MINTABLE_ERC721_FACTORY,
+            OpHardfork::Bedrock,
+            true,
+        ),
+        Predeploy::new("ProxyAdmin", Self::PROXY_ADMIN, OpHardfork::Bedrock, true),
+        Predeploy::new("BaseFeeVault", Self::BASE_FEE_VAULT, OpHardfork::Bedrock, true),
+        Predeploy::new("L1FeeVault", Self::L1_FEE_VAULT, OpHardfork::Bedrock, true),
+        Predeploy::new("SchemaRegistry", Self::SCHEMA_REGISTRY, OpHardfork::Bedrock, true),
+        Predeploy::new("EAS", Self::EAS, OpHardfork::Bedrock, true),
+        Predeploy::new("BeaconBlockRoot", Self::BEACON_BLOCK_ROOT, OpHardfork::Ecotone, false),
+        Predeploy::new("OperatorFeeVault", Self::OPERATOR_FEE_VAULT, OpHardfork::Isthmus, true),
+        Predeploy::new("CrossL2Inbox", Self::CROSS_L2_INBOX, OpHardfork::Interop, true),
+        Predeploy::new(
+            "L2ToL2CrossDomainMessenger",
+            Self::L2_TO_L2_CROSS_DOMAIN_MESSENGER,
+            OpHardfork::Interop,
+            true,
+        ),
+        Predeploy::new("SuperchainWETH", Self::SUPERCHAIN_WETH, OpHardfork::Interop, true),
+        Predeploy::new("ETHLiquidity", Self::ETH_LIQUIDITY, OpHardfork::Interop, true),
+        Predeploy::new(
+            "SuperchainTokenBridge",
+            Self::SUPERCHAIN_TOKEN_BRIDGE,
+            OpHardfork::Interop,
+            true,
+        ),
+    ];
+
+    /// The legacy contract `LegacyMessagePasser`, used to send messages to L1 before Bedrock.
+    pub const LEGACY_MESSAGE_PASSER: Address =
+        address!("0x4200000000000000000000000000000000000000");
+
+    /// The legacy contract `DeployerWhitelist`, used to restrict contract deployments before
+    /// Bedrock.
+    pub const DEPLOYER_WHITELIST: Address = address!("0x4200000000000000000000000000000000000002");
+
+    /// The legacy contract `LegacyERC20ETH`, represented ETH as an ERC20 token before Bedrock.
+    pub const LEGACY_ERC20_ETH: Address = address!("0xDeadDeAddeAddEAddeadDEaDDEAdDeaDDeAD0000");
+
+    /// The `WETH9` contract, the canonical wrapped ether.
+    pub const WETH9: Address = address!("0x4200000000000000000000000000000000000006");
+
+    /// The L2 contract `L2CrossDomainMessenger`, sends and relays messages between L1 and L2.
+    pub const L2_CROSS_DOMAIN_MESSENGER: Address =
+        address!("0x4200000000000000000000000000000000000007");
+
+    /// The L2 contract `L2StandardBridge`, bridges ETH and ERC20 tokens between L1 and L2.
+    pub const L2_STANDARD_BRIDGE: Address = address!("0x4200000000000000000000000000000000000010");
+
+    /// The L2 contract `SequencerFeeVault`, receives the priority fees of L2 transactions.
+    pub const SEQUENCER_FEE_VAULT: Address = address!("0x4200000000000000000000000000000000000011");
+
+    /// The L2 contract `OptimismMintableERC20Factory`, creates L2 representations of L1 ERC20
+    /// tokens.
+    pub const OPTIMISM_MINTABLE_ERC20_FACTORY: Address =
+        address!("0x4200000000000000000000000000000000000012");
+
+    /// The legacy contract `L1BlockNumber`, returns the last known L1 block number.
+    pub const L1_BLOCK_NUMBER: Address = address!("0x4200000000000000000000000000000000000013");
+
+    /// The L2 contract `GasPriceOracle`, exposes the L1 fee parameters.
+    pub const GAS_PRICE_ORACLE: Address = address!("0x420000000000000000000000000000000000000F");
+
+    /// The `GovernanceToken` contract, the OP token.
+    pub const GOVERNANCE_TOKEN: Address = address!("0x4200000000000000000000000000000000000042");
+
+    /// The L2 contract `L1Block`, stores attributes of the latest known L1 block.
+    pub const L1_BLOCK: Address = address!("0x4200000000000000000000000000000000000015");
 
     /// The L2 contract `L2ToL1MessagePasser`, stores commitments to withdrawal transactions.
     pub const L2_TO_L1_MESSAGE_PASSER: Address =
         address!("0x4200000000000000000000000000000000000016");
+
+    /// The L2 contract `L2ERC721Bridge`, bridges ERC721 tokens between L1 and L2.
+    pub const L2_ERC721_BRIDGE: Address = address!("0x4200000000000000000000000000000000000014");
+
+    /// The L2 contract `OptimismMintableERC721Factory`, creates L2 representations of L1 ERC721
+    /// tokens.
+    pub const OPTIMISM_MINTABLE_ERC721_FACTORY: Address =
+        address!("0x4200000000000000000000000000000000000017");
+
+    /// The L2 contract `ProxyAdmin`, the owner of all proxied predeploys.
+    pub const PROXY_ADMIN: Address = address!("0x4200000000000000000000000000000000000018");
+
+    /// The L2 contract `BaseFeeVault`, receives the base fees of L2 transactions.
+    pub const BASE_FEE_VAULT: Address = address!("0x4200000000000000000000000000000000000019");
+
+    /// The L2 contract `L1FeeVault`, receives the L1 data fees of L2 transactions.
+    pub const L1_FEE_VAULT: Address = address!("0x420000000000000000000000000000000000001a");
+
+    /// The `SchemaRegistry` contract of the Ethereum Attestation Service.
+    pub const SCHEMA_REGISTRY: Address = address!("0x4200000000000000000000000000000000000020");
+
+    /// The `EAS` contract of the Ethereum Attestation Service.
+    pub const EAS: Address = address!("0x4200000000000000000000000000000000000021");
+
+    /// The EIP-4788 `BeaconBlockRoot` contract, stores the parent beacon block roots.
+    pub const BEACON_BLOCK_ROOT: Address = address!("0x000F3df6D732807Ef1319fB7B8bB8522d0Beac02");
+
+    /// The L2 contract `OperatorFeeVault`, receives the operator fees of L2 transactions.
+    pub const OPERATOR_FEE_VAULT: Address = address!("0x420000000000000000000000000000000000001b");
+
+    /// The L2 contract `CrossL2Inbox`, validates executing messages of other chains.
+    pub const CROSS_L2_INBOX: Address = address!("0x4200000000000000000000000000000000000022");
+
+    /// The L2 contract `L2ToL2CrossDomainMessenger`, sends and relays messages between L2 chains.
+    pub const L2_TO_L2_CROSS_DOMAIN_MESSENGER: Address =
+        address!("0x4200000000000000000000000000000000000023");
+
+    /// The L2 contract `SuperchainWETH`, wrapped ether that can move across the superchain.
+    pub const SUPERCHAIN_WETH: Address = address!("0x4200000000000000000000000000000000000024");
+
+    /// The L2 contract `ETHLiquidity`, provides ether liquidity to `SuperchainWETH`.
+    pub const ETH_LIQUIDITY: Address = address!("0x4200000000000000000000000000000000000025");
+
+    /// The L2 contract `SuperchainTokenBridge`, bridges superchain ERC20 tokens between L2
+    /// chains.
+    pub const SUPERCHAIN_TOKEN_BRIDGE: Address =
+        address!("0x4200000000000000000000000000000000000028");
+
+    /// Returns an iterator over the metadata of all predeploys.
+    pub fn iter() -> impl Iterator<Item = &'static Predeploy> {
+        Self::METADATA.iter()
+    }
+
+    /// Returns the metadata of the predeploy at the given address, if any.
+    pub fn by_address(address: Address) -> Option<&'static Predeploy> {
+        Self::iter().find(|p| p.address == address)
+    }
+
+    /// Returns the metadata of the predeploy with the given contract name, if any.
+    ///
+    /// The name is matched case-insensitively.
+    pub fn by_name(name: &str) -> Option<&'static Predeploy> {
+        Self::iter().find(|p| p.name.eq_ignore_ascii_case(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::map::HashSet;
+
+    #[test]
+    fn test_metadata_matches_all() {
+        let addresses = Predeploys::iter().map(|p| p.address).collect::<alloc::vec::Vec<_>>();
+        assert_eq!(addresses, Predeploys::ALL);
+    }
+
+    #[test]
+    fn test_predeploys_unique() {
+        let addresses = Predeploys::ALL.iter().collect::<HashSet<_>>();
+        assert_eq!(addresses.len(), Predeploys::ALL.len());
+        let names = Predeploys::iter().map(|p| p.name).collect::<HashSet<_>>();
+        assert_eq!(names.len(), Predeploys::ALL.len());
+    }
+
+    #[test]
+    fn test_by_address() {
+        let predeploy = Predeploys::by_address(Predeploys::L1_BLOCK).unwrap();
+        assert_eq!(predeploy.name, "L1Block");
+        assert_eq!(predeploy.introduced, OpHardfork::Bedrock);
+        assert!(predeploy.proxied);
+        assert!(Predeploys::by_address(Address::ZERO).is_none());
+    }
+
+    #[test]
+    fn test_by_name() {
+        let predeploy = Predeploys::by_name("CrossL2Inbox").unwrap();
+        assert_eq!(predeploy.address, Predeploys::CROSS_L2_INBOX);
+        assert_eq!(predeploy.introduced, OpHardfork::Interop);
+        assert_eq!(Predeploys::by_name("weth9").unwrap().address, Predeploys::WETH9);
+        assert!(!Predeploys::by_name("WETH9").unwrap().proxied);
+        assert!(Predeploys::by_name("Unknown").is_none());
+    }
 }
@@ -0,0 +1,212 @@
+//! Contains the L1 fee fields attached to OP Stack transaction receipts.
+
+use crate::{starts_with_2718_deposit, L1BlockInfoTx, L1FeeCalculator};
+use alloc::vec::Vec;
+use alloy_eips::eip2718::Encodable2718;
+use alloy_primitives::U256;
+use maili_genesis::RollupConfig;
+use op_alloy_consensus::OpBlock;
+
+/// The L1 fee fields of the receipt of a non-deposit transaction, as returned by the OP Stack
+/// `eth_getTransactionReceipt` RPC.
+///
+/// Scalars that are not defined by the hardfork active at the block timestamp are omitted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct L1ReceiptFields {
+    /// The L1 data fee charged to the transaction.
+    pub l1_fee: U256,
+    /// The L1 gas used by the transaction data.
+    pub l1_gas_used: U256,
+    /// The L1 base fee.
+    pub l1_gas_price: U256,
+    /// The L1 base fee scalar, since Ecotone.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub l1_base_fee_scalar: Option<U256>,
+    /// The L1 blob base fee scalar, since Ecotone.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub l1_blob_base_fee_scalar: Option<U256>,
+    /// The operator fee scalar, since Isthmus.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "alloy_serde::quantity::opt"
+        )
+    )]
+    pub operator_fee_scalar: Option<u64>,
+    /// The operator fee constant, since Isthmus.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "alloy_serde::quantity::opt"
+        )
+    )]
+    pub operator_fee_constant: Option<u64>,
+}
+
+impl L1ReceiptFields {
+    /// Computes the [L1ReceiptFields] of the raw EIP-2718 encoded transaction, included in a
+    /// block with the given timestamp.
+    ///
+    /// Returns [None] for deposit transactions, which do not carry L1 fee fields.
+    pub fn from_tx(
+        config: &RollupConfig,
+        l1_info: &L1BlockInfoTx,
+        tx: &[u8],
+        timestamp: u64,
+    ) -> Option<Self> {
+        if starts_with_2718_deposit(&tx) {
+            return None;
+        }
+
+        let calculator = L1FeeCalculator::new(config, l1_info);
+        let ecotone = config.is_ecotone_active(timestamp) && !l1_info.empty_scalars();
+        let isthmus = config.is_isthmus_active(timestamp);
+        Some(Self {
+            l1_fee: calculator.l1_fee(tx, timestamp),
+            l1_gas_used: calculator.l1_gas_used(tx, timestamp),
+            l1_gas_price: l1_info.l1_base_fee(),
+            l1_base_fee_scalar: ecotone.then(|| l1_info.l1_fee_scalar()),
+            l1_blob_base_fee_scalar: ecotone.then(|| l1_info.blob_base_fee_scalar()),
+            operator_fee_scalar: isthmus.then(|| l1_info.operator_fee_scalar() as u64),
+            operator_fee_constant: isthmus.then(|| l1_info.operator_fee_constant()),
+        })
+    }
+}
+
+/// Computes the [L1ReceiptFields] of every transaction in the block, given the [L1BlockInfoTx]
+/// of the block.
+///
+/// The returned list is index-aligned with the block transactions, deposit transactions being
+/// skipped as [None].
+pub fn l1_receipt_fields(
+    config: &RollupConfig,
+    block: &OpBlock,
+    l1_info: &L1BlockInfoTx,
+) -> Vec<Option<L1ReceiptFields>> {
+    let timestamp = block.header.timestamp;
+    block
+        .body
+        .transactions
+        .iter()
+        .map(|tx| L1ReceiptFields::from_tx(config, l1_info, &tx.encoded_2718(), timestamp))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{L1BlockInfoBedrock, L1BlockInfoIsthmus};
+    use alloc::vec;
+    use alloy_consensus::{Header, Signed, TxEip1559};
+    use alloy_primitives::{PrimitiveSignature, Sealable, B256};
+    use op_alloy_consensus::{OpTxEnvelope, TxDeposit};
+
+    fn block(timestamp: u64) -> OpBlock {
+        let deposit = OpTxEnvelope::Deposit(TxDeposit::default().seal_slow());
+        let tx = OpTxEnvelope::Eip1559(Signed::new_unchecked(
+            TxEip1559::default(),
+            PrimitiveSignature::test_signature(),
+            B256::ZERO,
+        ));
+        OpBlock {
+            header: Header { timestamp, ..Default::default() },
+            body: alloy_consensus::BlockBody {
+                transactions: vec![deposit, tx],
+                ommers: Default::default(),
+                withdrawals: None,
+            },
+        }
+    }
+
+    fn isthmus_info() -> L1BlockInfoTx {
+        L1BlockInfoTx::Isthmus(L1BlockInfoIsthmus {
+            base_fee: 1_000,
+            base_fee_scalar: 2_000,
+            blob_base_fee: 3_000,
+            blob_base_fee_scalar: 4_000,
+            operator_fee_scalar: 5_000,
+            operator_fee_constant: 6_000,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_l1_receipt_fields_skips_deposits() {
+        let config = RollupConfig { isthmus_time: Some(0), ..Default::default() };
+        let fields = l1_receipt_fields(&config, &block(0), &isthmus_info());
+        assert_eq!(fields.len(), 2);
+        assert!(fields[0].is_none());
+        assert!(fields[1].is_some());
+    }
+
+    #[test]
+    fn test_l1_receipt_fields_isthmus() {
+        let config = RollupConfig { isthmus_time: Some(0), ..Default::default() };
+        let info = isthmus_info();
+        let block = block(0);
+        let tx = block.body.transactions[1].encoded_2718();
+        let calculator = L1FeeCalculator::new(&config, &info);
+
+        let fields = l1_receipt_fields(&config, &block, &info)[1].unwrap();
+        assert_eq!(
+            fields,
+            L1ReceiptFields {
+                l1_fee: calculator.l1_fee(&tx, 0),
+                l1_gas_used: calculator.l1_gas_used(&tx, 0),
+                l1_gas_price: U256::from(1_000),
+                l1_base_fee_scalar: Some(U256::from(2_000)),
+                l1_blob_base_fee_scalar: Some(U256::from(4_000)),
+                operator_fee_scalar: Some(5_000),
+                operator_fee_constant: Some(6_000),
+            }
+        );
+        assert_ne!(fields.l1_fee, U256::ZERO);
+    }
+
+    #[test]
+    fn test_l1_receipt_fields_bedrock() {
+        let config = RollupConfig::default();
+        let info = L1BlockInfoTx::Bedrock(L1BlockInfoBedrock {
+            base_fee: 1_000,
+            l1_fee_overhead: U256::from(188),
+            l1_fee_scalar: U256::from(684_000),
+            ..Default::default()
+        });
+
+        let fields = l1_receipt_fields(&config, &block(0), &info)[1].unwrap();
+        assert_eq!(fields.l1_gas_price, U256::from(1_000));
+        assert!(fields.l1_base_fee_scalar.is_none());
+        assert!(fields.l1_blob_base_fee_scalar.is_none());
+        assert!(fields.operator_fee_scalar.is_none());
+        assert!(fields.operator_fee_constant.is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_l1_receipt_fields_serde() {
+        let fields = L1ReceiptFields {
+            l1_fee: U256::from(1),
+            l1_gas_used: U256::from(2),
+            l1_gas_price: U256::from(3),
+            operator_fee_scalar: Some(4),
+            ..Default::default()
+        };
+        let json = serde_json::to_value(fields).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "l1Fee": "0x1",
+                "l1GasUsed": "0x2",
+                "l1GasPrice": "0x3",
+                "operatorFeeScalar": "0x4",
+            })
+        );
+        assert_eq!(serde_json::from_value::<L1ReceiptFields>(json).unwrap(), fields);
+    }
+}
@@ -33,6 +33,8 @@ pub struct MockCompressor {
     pub compressed: Option<Bytes>,
     /// Whether to throw a read error.
     pub read_error: bool,
+    /// Whether the compressor reports itself as full.
+    pub is_full: bool,
 }
 
 impl CompressorWriter for MockCompressor {
@@ -59,6 +61,10 @@ impl CompressorWriter for MockCompressor {
         self.compressed.as_ref().map(|b| b.len()).unwrap_or(0)
     }
 
+    fn is_full(&self) -> bool {
+        self.is_full
+    }
+
     fn read(&mut self, buf: &mut [u8]) -> CompressorResult<usize> {
         if self.read_error {
             return Err(CompressorError::Full);
@@ -0,0 +1,384 @@
+//! Contains withdrawal transaction types and helpers to prove them against an L2 output.
+//!
+//! Withdrawals are initiated on L2 through the `L2ToL1MessagePasser` predeploy, which records the
+//! hash of each [WithdrawalTransaction] in its `sentMessages` mapping and emits a
+//! [MessagePassed] event.
+//!
+//! See: <https://specs.optimism.io/protocol/withdrawals.html>
+
+use crate::Predeploys;
+use alloc::{boxed::Box, vec::Vec};
+use alloy_primitives::{keccak256, Address, Bytes, Log, B256, U256};
+use alloy_sol_types::{sol, SolEvent, SolValue};
+use alloy_trie::{
+    proof::{verify_proof, ProofVerificationError},
+    Nibbles, TrieAccount,
+};
+
+sol! {
+    /// Emitted by the `L2ToL1MessagePasser` whenever a withdrawal is initiated.
+    #[derive(Debug, PartialEq, Eq)]
+    event MessagePassed(
+        uint256 indexed nonce,
+        address indexed sender,
+        address indexed target,
+        uint256 value,
+        uint256 gasLimit,
+        bytes data,
+        bytes32 withdrawalHash
+    );
+}
+
+/// The storage slot of the `sentMessages` mapping in the `L2ToL1MessagePasser`.
+pub const SENT_MESSAGES_SLOT: U256 = U256::ZERO;
+
+/// An error returned when decoding or proving a [WithdrawalTransaction].
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum WithdrawalError {
+    /// The log was not emitted by the `L2ToL1MessagePasser`.
+    #[error("Log emitted by {0}, not the L2ToL1MessagePasser")]
+    UnexpectedEmitter(Address),
+    /// The log is not a valid `MessagePassed` event.
+    #[error("Invalid MessagePassed event")]
+    InvalidEvent,
+    /// The withdrawal hash of the event does not match the hash of the decoded withdrawal.
+    #[error("Withdrawal hash mismatch: event {event}, computed {computed}")]
+    HashMismatch {
+        /// The withdrawal hash emitted in the event.
+        event: B256,
+        /// The hash of the decoded withdrawal.
+        computed: B256,
+    },
+    /// The storage root of the proven account does not match the expected storage root.
+    #[error("Storage root mismatch: expected {expected}, got {got}")]
+    StorageRootMismatch {
+        /// The expected message passer storage root.
+        expected: B256,
+        /// The storage root of the proven account.
+        got: B256,
+    },
+    /// The account proof of the `L2ToL1MessagePasser` is invalid.
+    #[error("Invalid account proof: {0}")]
+    AccountProof(Box<ProofVerificationError>),
+    /// The storage proof of the `sentMessages` slot is invalid.
+    #[error("Invalid storage proof: {0}")]
+    StorageProof(Box<ProofVerificationError>),
+}
+
+/// A withdrawal transaction, sent from L2 to L1 through the `L2ToL1MessagePasser`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct WithdrawalTransaction {
+    /// The nonce of the withdrawal, as assigned by the message passer.
+    pub nonce: U256,
+    /// The address initiating the withdrawal on L2.
+    pub sender: Address,
+    /// The address called on L1.
+    pub target: Address,
+    /// The amount of ether sent to the target.
+    pub value: U256,
+    /// The minimum gas limit of the L1 call.
+    pub gas_limit: U256,
+    /// The calldata of the L1 call.
+    pub data: Bytes,
+}
+
+impl WithdrawalTransaction {
+    /// Decodes a [WithdrawalTransaction] from a `MessagePassed` log of the
+    /// `L2ToL1MessagePasser`.
+    ///
+    /// The withdrawal hash emitted in the event is checked against the hash of the decoded
+    /// withdrawal.
+    pub fn decode_message_passed(log: &Log) -> Result<Self, WithdrawalError> {
+        if log.address != Predeploys::L2_TO_L1_MESSAGE_PASSER {
+            return Err(WithdrawalError::UnexpectedEmitter(log.address));
+        }
+        let event = MessagePassed::decode_log_data(&log.data, true)
+            .map_err(|_| WithdrawalError::InvalidEvent)?;
+        let withdrawal = Self {
+            nonce: event.nonce,
+            sender: event.sender,
+            target: event.target,
+            value: event.value,
+            gas_limit: event.gasLimit,
+            data: event.data,
+        };
+        let computed = withdrawal.hash();
+        if computed != event.withdrawalHash {
+            return Err(WithdrawalError::HashMismatch { event: event.withdrawalHash, computed });
+        }
+        Ok(withdrawal)
+    }
+
+    /// Returns the hash of the withdrawal. See [hash_withdrawal].
+    pub fn hash(&self) -> B256 {
+        hash_withdrawal(self)
+    }
+
+    /// Returns the slot of the withdrawal in the `sentMessages` mapping. See
+    /// [sent_messages_slot].
+    pub fn storage_slot(&self) -> B256 {
+        sent_messages_slot(self.hash())
+    }
+}
+
+/// Hashes a [WithdrawalTransaction], as done by `Hashing.hashWithdrawal` in the contracts.
+///
+/// This is the keccak256 hash of the ABI encoded nonce, sender, target, value, gas limit and
+/// data of the withdrawal.
+pub fn hash_withdrawal(withdrawal: &WithdrawalTransaction) -> B256 {
+    let encoded = (
+        withdrawal.nonce,
+        withdrawal.sender,
+        withdrawal.target,
+        withdrawal.value,
+        withdrawal.gas_limit,
+        withdrawal.data.clone(),
+    )
+        .abi_encode_params();
+    keccak256(encoded)
+}
+
+/// Returns the storage slot of a withdrawal hash in the `sentMessages` mapping of the
+/// `L2ToL1MessagePasser`.
+pub fn sent_messages_slot(withdrawal_hash: B256) -> B256 {
+    keccak256((withdrawal_hash, SENT_MESSAGES_SLOT).abi_encode())
+}
+
+/// A merkle proof of a withdrawal, as returned by `eth_getProof` for the `L2ToL1MessagePasser`
+/// and the `sentMessages` slot of the withdrawal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WithdrawalProof {
+    /// The `L2ToL1MessagePasser` account.
+    pub account: TrieAccount,
+    /// The proof of the account against the state root.
+    pub account_proof: Vec<Bytes>,
+    /// The proof of the `sentMessages` slot against the storage root of the account.
+    pub storage_proof: Vec<Bytes>,
+}
+
+/// Verifies that a withdrawal is included in the `sentMessages` mapping of the
+/// `L2ToL1MessagePasser` at the given state root.
+///
+/// The `message_passer_storage_root` is the storage root committed to by the output root, which
+/// the proven account must match.
+pub fn verify_withdrawal_proof(
+    withdrawal_hash: B256,
+    state_root: B256,
+    message_passer_storage_root: B256,
+    proof: &WithdrawalProof,
+) -> Result<(), WithdrawalError> {
+    if proof.account.storage_root != message_passer_storage_root {
+        return Err(WithdrawalError::StorageRootMismatch {
+            expected: message_passer_storage_root,
+            got: proof.account.storage_root,
+        });
+    }
+
+    let account_key = Nibbles::unpack(keccak256(Predeploys::L2_TO_L1_MESSAGE_PASSER));
+    verify_proof(
+        state_root,
+        account_key,
+        Some(alloy_rlp::encode(proof.account)),
+        &proof.account_proof,
+    )
+    .map_err(|e| WithdrawalError::AccountProof(Box::new(e)))?;
+
+    // A sent message is stored as `true`.
+    let storage_key = Nibbles::unpack(keccak256(sent_messages_slot(withdrawal_hash)));
+    verify_proof(
+        message_passer_storage_root,
+        storage_key,
+        Some(alloy_rlp::encode(U256::from(1))),
+        &proof.storage_proof,
+    )
+    .map_err(|e| WithdrawalError::StorageProof(Box::new(e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use alloy_primitives::{address, bytes, LogData};
+    use alloy_trie::{proof::ProofRetainer, HashBuilder};
+
+    fn withdrawal() -> WithdrawalTransaction {
+        WithdrawalTransaction {
+            nonce: U256::from(1) << 240 | U256::from(7),
+            sender: address!("1111111111111111111111111111111111111111"),
+            target: address!("2222222222222222222222222222222222222222"),
+            value: U256::from(1_000_000_000_000_000_000u128),
+            gas_limit: U256::from(100_000),
+            data: bytes!("deadbeef"),
+        }
+    }
+
+    fn message_passed_log(withdrawal: &WithdrawalTransaction, withdrawal_hash: B256) -> Log {
+        let event = MessagePassed {
+            nonce: withdrawal.nonce,
+            sender: withdrawal.sender,
+            target: withdrawal.target,
+            value: withdrawal.value,
+            gasLimit: withdrawal.gas_limit,
+            data: withdrawal.data.clone(),
+            withdrawalHash: withdrawal_hash,
+        };
+        Log { address: Predeploys::L2_TO_L1_MESSAGE_PASSER, data: event.encode_log_data() }
+    }
+
+    /// Builds a trie from the given hashed keys and values, returning its root and the proof of
+    /// `target`.
+    fn trie_proof(leaves: &[(B256, Vec<u8>)], target: B256) -> (B256, Vec<Bytes>) {
+        let target = Nibbles::unpack(target);
+        let mut leaves = leaves
+            .iter()
+            .map(|(key, value)| (Nibbles::unpack(key), value.clone()))
+            .collect::<Vec<_>>();
+        leaves.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut builder =
+            HashBuilder::default().with_proof_retainer(ProofRetainer::new(vec![target.clone()]));
+        for (key, value) in &leaves {
+            builder.add_leaf(key.clone(), value);
+        }
+        let root = builder.root();
+        let proof = builder
+            .take_proof_nodes()
+            .matching_nodes_sorted(&target)
+            .into_iter()
+            .map(|(_, node)| node)
+            .collect();
+        (root, proof)
+    }
+
+    fn withdrawal_proof(withdrawal_hash: B256) -> (B256, B256, WithdrawalProof) {
+        let slot = sent_messages_slot(withdrawal_hash);
+        let storage = [slot, B256::with_last_byte(1), B256::with_last_byte(2)]
+            .map(|slot| (keccak256(slot), alloy_rlp::encode(U256::from(1))));
+        let (storage_root, storage_proof) = trie_proof(&storage, keccak256(slot));
+
+        let account = TrieAccount { storage_root, ..Default::default() };
+        let accounts = [
+            (Predeploys::L2_TO_L1_MESSAGE_PASSER, account),
+            (Predeploys::L1_BLOCK, TrieAccount::default()),
+            (Predeploys::WETH9, TrieAccount::default()),
+        ]
+        .map(|(address, account)| (keccak256(address), alloy_rlp::encode(account)));
+        let (state_root, account_proof) =
+            trie_proof(&accounts, keccak256(Predeploys::L2_TO_L1_MESSAGE_PASSER));
+
+        (state_root, storage_root, WithdrawalProof { account, account_proof, storage_proof })
+    }
+
+    #[test]
+    fn test_message_passed_signature() {
+        assert_eq!(
+            MessagePassed::SIGNATURE,
+            "MessagePassed(uint256,address,address,uint256,uint256,bytes,bytes32)"
+        );
+    }
+
+    #[test]
+    fn test_hash_withdrawal() {
+        let withdrawal = withdrawal();
+        // abi.encode(nonce, sender, target, value, gasLimit, data)
+        let mut encoded = Vec::new();
+        encoded.extend_from_slice(&withdrawal.nonce.to_be_bytes::<32>());
+        encoded.extend_from_slice(withdrawal.sender.into_word().as_slice());
+        encoded.extend_from_slice(withdrawal.target.into_word().as_slice());
+        encoded.extend_from_slice(&withdrawal.value.to_be_bytes::<32>());
+        encoded.extend_from_slice(&withdrawal.gas_limit.to_be_bytes::<32>());
+        encoded.extend_from_slice(&U256::from(6 * 32).to_be_bytes::<32>());
+        encoded.extend_from_slice(&U256::from(4).to_be_bytes::<32>());
+        encoded.extend_from_slice(&[0xde, 0xad, 0xbe, 0xef]);
+        encoded.extend_from_slice(&[0u8; 28]);
+        assert_eq!(hash_withdrawal(&withdrawal), keccak256(&encoded));
+        assert_eq!(withdrawal.hash(), keccak256(&encoded));
+    }
+
+    #[test]
+    fn test_sent_messages_slot() {
+        let hash = B256::repeat_byte(0xab);
+        let preimage = [hash.as_slice(), &[0u8; 32]].concat();
+        assert_eq!(sent_messages_slot(hash), keccak256(preimage));
+        assert_eq!(withdrawal().storage_slot(), sent_messages_slot(withdrawal().hash()));
+    }
+
+    #[test]
+    fn test_decode_message_passed() {
+        let withdrawal = withdrawal();
+        let log = message_passed_log(&withdrawal, withdrawal.hash());
+        assert_eq!(WithdrawalTransaction::decode_message_passed(&log).unwrap(), withdrawal);
+    }
+
+    #[test]
+    fn test_decode_message_passed_unexpected_emitter() {
+        let withdrawal = withdrawal();
+        let mut log = message_passed_log(&withdrawal, withdrawal.hash());
+        log.address = Address::ZERO;
+        assert_eq!(
+            WithdrawalTransaction::decode_message_passed(&log),
+            Err(WithdrawalError::UnexpectedEmitter(Address::ZERO))
+        );
+    }
+
+    #[test]
+    fn test_decode_message_passed_invalid_event() {
+        let log = Log {
+            address: Predeploys::L2_TO_L1_MESSAGE_PASSER,
+            data: LogData::new_unchecked(vec![B256::ZERO], Bytes::new()),
+        };
+        assert_eq!(
+            WithdrawalTransaction::decode_message_passed(&log),
+            Err(WithdrawalError::InvalidEvent)
+        );
+    }
+
+    #[test]
+    fn test_decode_message_passed_hash_mismatch() {
+        let withdrawal = withdrawal();
+        let log = message_passed_log(&withdrawal, B256::ZERO);
+        assert_eq!(
+            WithdrawalTransaction::decode_message_passed(&log),
+            Err(WithdrawalError::HashMismatch { event: B256::ZERO, computed: withdrawal.hash() })
+        );
+    }
+
+    #[test]
+    fn test_verify_withdrawal_proof() {
+        let hash = withdrawal().hash();
+        let (state_root, storage_root, proof) = withdrawal_proof(hash);
+        assert_eq!(verify_withdrawal_proof(hash, state_root, storage_root, &proof), Ok(()));
+    }
+
+    #[test]
+    fn test_verify_withdrawal_proof_storage_root_mismatch() {
+        let hash = withdrawal().hash();
+        let (state_root, storage_root, proof) = withdrawal_proof(hash);
+        assert_eq!(
+            verify_withdrawal_proof(hash, state_root, B256::ZERO, &proof),
+            Err(WithdrawalError::StorageRootMismatch { expected: B256::ZERO, got: storage_root })
+        );
+    }
+
+    #[test]
+    fn test_verify_withdrawal_proof_invalid_account_proof() {
+        let hash = withdrawal().hash();
+        let (_, storage_root, proof) = withdrawal_proof(hash);
+        assert!(matches!(
+            verify_withdrawal_proof(hash, B256::ZERO, storage_root, &proof),
+            Err(WithdrawalError::AccountProof(_))
+        ));
+    }
+
+    #[test]
+    fn test_verify_withdrawal_proof_unknown_withdrawal() {
+        let hash = withdrawal().hash();
+        let (state_root, storage_root, proof) = withdrawal_proof(hash);
+        assert!(matches!(
+            verify_withdrawal_proof(B256::ZERO, state_root, storage_root, &proof),
+            Err(WithdrawalError::StorageProof(_))
+        ));
+    }
+}
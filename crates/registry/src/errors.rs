@@ -0,0 +1,28 @@
+//! Error types for the registry.
+
+use alloc::string::String;
+
+/// An error encountered while loading the registry from superchain-registry data.
+#[derive(Debug, thiserror::Error)]
+pub enum RegistryError {
+    /// Failed to read a registry file.
+    #[cfg(feature = "std")]
+    #[error("Failed to read registry file: {0}")]
+    Io(#[from] std::io::Error),
+    /// Failed to parse a JSON registry file.
+    #[error("Failed to parse JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    /// Failed to parse a TOML registry file.
+    #[cfg(feature = "std")]
+    #[error("Failed to parse TOML: {0}")]
+    Toml(#[from] toml::de::Error),
+    /// A superchain directory is missing its `superchain.toml` config.
+    #[error("Missing superchain.toml for superchain {0}")]
+    MissingSuperchainConfig(String),
+    /// A superchain config is missing the protocol versions address.
+    #[error("Missing protocol versions address for superchain {0}")]
+    MissingProtocolVersionsAddress(String),
+    /// The same chain ID is configured more than once.
+    #[error("Duplicate config for chain ID {0}")]
+    DuplicateChainId(u64),
+}
@@ -12,11 +12,14 @@ extern crate alloc;
 pub use alloy_primitives::map::{DefaultHashBuilder, HashMap};
 pub use maili_genesis::{ChainConfig, RollupConfig};
 
+mod errors;
+pub use errors::RegistryError;
+
 pub mod chain_list;
 pub use chain_list::{Chain, ChainList};
 
 pub mod superchain;
-pub use superchain::Registry;
+pub use superchain::{HardforkActivation, Registry};
 
 #[cfg(test)]
 pub mod test_utils;
@@ -1,9 +1,22 @@
 //! Contains the full superchain data.
 
-use super::Chain;
+use crate::{Chain, RegistryError};
 use alloc::vec::Vec;
 use alloy_primitives::map::{DefaultHashBuilder, HashMap};
-use maili_genesis::{ChainConfig, RollupConfig, Superchains};
+use maili_genesis::{ChainConfig, OpHardfork, RollupConfig, SuperchainLevel, Superchains};
+#[cfg(feature = "std")]
+use maili_genesis::{Superchain, SuperchainConfig};
+
+/// A scheduled hardfork activation of a chain in the registry.
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub struct HardforkActivation {
+    /// The chain ID.
+    pub chain_id: u64,
+    /// The activated hardfork.
+    pub fork: OpHardfork,
+    /// The activation timestamp.
+    pub timestamp: u64,
+}
 
 /// The registry containing all the superchain configurations.
 #[derive(Debug, Clone, Default, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -32,29 +45,168 @@ impl Registry {
 
     /// Initialize the superchain configurations from the chain list.
     pub fn from_chain_list() -> Self {
-        let chains = Self::read_chain_list();
-        let superchains = Self::read_superchain_configs();
+        Self::from_parts(Self::read_chain_list(), Self::read_superchain_configs())
+            .expect("Invalid embedded superchain configs")
+    }
+
+    /// Builds the registry from a chain list and the superchain configs, deriving the
+    /// [RollupConfig] of every chain.
+    pub fn from_parts(chains: Vec<Chain>, superchains: Superchains) -> Result<Self, RegistryError> {
         let mut op_chains = HashMap::default();
         let mut rollup_configs = HashMap::default();
 
         for superchain in superchains.superchains {
+            let protocol_versions_address = superchain
+                .config
+                .protocol_versions_addr
+                .ok_or(RegistryError::MissingProtocolVersionsAddress(superchain.name))?;
             for mut chain_config in superchain.chains {
+                if op_chains.contains_key(&chain_config.chain_id) {
+                    return Err(RegistryError::DuplicateChainId(chain_config.chain_id));
+                }
                 chain_config.l1_chain_id = superchain.config.l1.chain_id;
+                chain_config.inherit_superchain_hardforks(&superchain.config.hardforks);
                 if let Some(a) = &mut chain_config.addresses {
                     a.zero_proof_addresses();
                 }
                 let mut rollup = chain_config.as_rollup_config();
-                rollup.protocol_versions_address = superchain
-                    .config
-                    .protocol_versions_addr
-                    .expect("Missing protocol versions address");
+                rollup.protocol_versions_address = protocol_versions_address;
                 rollup.superchain_config_address = superchain.config.superchain_config_addr;
                 rollup_configs.insert(chain_config.chain_id, rollup);
                 op_chains.insert(chain_config.chain_id, chain_config);
             }
         }
 
-        Self { chains, op_chains, rollup_configs }
+        Ok(Self { chains, op_chains, rollup_configs })
+    }
+
+    /// Builds the registry from a `chainList.json` and a superchain configs JSON in the format of
+    /// the embedded `etc/configs.json`.
+    #[cfg(feature = "std")]
+    pub fn from_json(
+        chain_list: impl std::io::Read,
+        configs: impl std::io::Read,
+    ) -> Result<Self, RegistryError> {
+        Self::from_parts(serde_json::from_reader(chain_list)?, serde_json::from_reader(configs)?)
+    }
+
+    /// Builds the registry from a local superchain-registry checkout, reading its `chainList.json`
+    /// and the TOML configs under `superchain/configs`.
+    ///
+    /// Every directory of `superchain/configs` is a superchain, named after the directory, whose
+    /// `superchain.toml` holds the superchain config and whose other TOML files each hold a
+    /// [ChainConfig].
+    #[cfg(feature = "std")]
+    pub fn from_dir(path: impl AsRef<std::path::Path>) -> Result<Self, RegistryError> {
+        let path = path.as_ref();
+        let chains = serde_json::from_reader(std::fs::File::open(path.join("chainList.json"))?)?;
+
+        let mut superchains = Superchains::default();
+        for entry in std::fs::read_dir(path.join("superchain").join("configs"))? {
+            let dir = entry?.path();
+            if !dir.is_dir() {
+                continue;
+            }
+            let name = dir.file_name().unwrap_or_default().to_string_lossy().into_owned();
+
+            let mut config = None;
+            let mut chains = Vec::new();
+            for file in std::fs::read_dir(&dir)? {
+                let file = file?.path();
+                if file.extension() != Some(std::ffi::OsStr::new("toml")) {
+                    continue;
+                }
+                let contents = std::fs::read_to_string(&file)?;
+                if file.file_name().is_some_and(|f| f == "superchain.toml") {
+                    config = Some(toml::from_str::<SuperchainConfig>(&contents)?);
+                } else {
+                    chains.push(toml::from_str::<ChainConfig>(&contents)?);
+                }
+            }
+            let config =
+                config.ok_or_else(|| RegistryError::MissingSuperchainConfig(name.clone()))?;
+            chains.sort_by_key(|c| c.chain_id);
+            superchains.superchains.push(Superchain { name, config, chains });
+        }
+        superchains.superchains.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Self::from_parts(chains, superchains)
+    }
+
+    /// Returns the chain with the given chain ID.
+    pub fn chain(&self, chain_id: u64) -> Option<&Chain> {
+        self.chains.iter().find(|c| c.chain_id == chain_id)
+    }
+
+    /// Returns the chain with the given name, e.g. `OP Mainnet`. The name is matched
+    /// case-insensitively.
+    pub fn chain_by_name(&self, name: &str) -> Option<&Chain> {
+        self.chains.iter().find(|c| c.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the chain with the given identifier, e.g. `mainnet/op`.
+    pub fn chain_by_identifier(&self, identifier: &str) -> Option<&Chain> {
+        self.chains.iter().find(|c| c.identifier == identifier)
+    }
+
+    /// Returns the chain with the given identifier or name.
+    pub fn find_chain(&self, name_or_identifier: &str) -> Option<&Chain> {
+        self.chain_by_identifier(name_or_identifier)
+            .or_else(|| self.chain_by_name(name_or_identifier))
+    }
+
+    /// Returns the chains of the given superchain, e.g. `mainnet` or `sepolia`.
+    pub fn chains_in_superchain<'a>(
+        &'a self,
+        superchain: &'a str,
+    ) -> impl Iterator<Item = &'a Chain> + 'a {
+        self.chains.iter().filter(move |c| c.parent.chain == superchain)
+    }
+
+    /// Returns the chains with the given [SuperchainLevel].
+    pub fn chains_by_level(&self, level: SuperchainLevel) -> impl Iterator<Item = &Chain> + '_ {
+        self.chains.iter().filter(move |c| c.superchain_level == level as u64)
+    }
+
+    /// Returns the chains with the given data availability type, e.g. `eth-da` or `alt-da`.
+    pub fn chains_by_da_type<'a>(
+        &'a self,
+        da_type: &'a str,
+    ) -> impl Iterator<Item = &'a Chain> + 'a {
+        self.chains.iter().filter(move |c| c.data_availability_type == da_type)
+    }
+
+    /// Returns the chains on which the given hardfork is active at the given timestamp.
+    pub fn chains_with_fork_active(
+        &self,
+        fork: OpHardfork,
+        timestamp: u64,
+    ) -> impl Iterator<Item = &Chain> + '_ {
+        self.chains.iter().filter(move |c| {
+            self.rollup_configs.get(&c.chain_id).is_some_and(|r| r.is_fork_active(fork, timestamp))
+        })
+    }
+
+    /// Returns the hardfork activations scheduled strictly after the given timestamp, across all
+    /// chains, ordered by activation time and chain ID.
+    ///
+    /// The activation times are those of [RollupConfig::activation_time], so a hardfork implied
+    /// by a later one is listed as activating with it.
+    pub fn upcoming_activations(&self, timestamp: u64) -> Vec<HardforkActivation> {
+        let mut activations = self
+            .rollup_configs
+            .iter()
+            .flat_map(|(chain_id, config)| {
+                OpHardfork::ALL.into_iter().filter_map(move |fork| {
+                    config
+                        .activation_time(fork)
+                        .filter(|t| *t > timestamp)
+                        .map(|t| HardforkActivation { chain_id: *chain_id, fork, timestamp: t })
+                })
+            })
+            .collect::<Vec<_>>();
+        activations.sort_by_key(|a| (a.timestamp, a.chain_id, a.fork));
+        activations
     }
 }
 
@@ -63,7 +215,7 @@ mod tests {
     use super::*;
     use alloc::string::{String, ToString};
     use alloy_primitives::address;
-    use maili_genesis::{AddressList, Roles, SuperchainLevel, OP_MAINNET_BASE_FEE_CONFIG};
+    use maili_genesis::{AddressList, ChainGenesis, Roles, OP_MAINNET_BASE_FEE_CONFIG};
 
     #[test]
     fn test_read_chain_configs() {
@@ -138,6 +290,181 @@ mod tests {
         assert_eq!(*superchains.op_chains.get(&8453).unwrap(), base_config);
     }
 
+    #[test]
+    fn test_from_json() {
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("etc");
+        let registry = Registry::from_json(
+            std::fs::File::open(dir.join("chainList.json")).unwrap(),
+            std::fs::File::open(dir.join("configs.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(registry, Registry::from_chain_list());
+    }
+
+    #[test]
+    fn test_from_parts_validation() {
+        let mut superchains = Registry::read_superchain_configs();
+        superchains.superchains[0].config.protocol_versions_addr = None;
+        let name = superchains.superchains[0].name.clone();
+        assert!(matches!(
+            Registry::from_parts(Vec::new(), superchains),
+            Err(RegistryError::MissingProtocolVersionsAddress(n)) if n == name
+        ));
+
+        let mut superchains = Registry::read_superchain_configs();
+        let chain = superchains.superchains[0].chains[0].clone();
+        superchains.superchains[1].chains.push(chain.clone());
+        assert!(matches!(
+            Registry::from_parts(Vec::new(), superchains),
+            Err(RegistryError::DuplicateChainId(id)) if id == chain.chain_id
+        ));
+    }
+
+    #[test]
+    fn test_from_parts_inherits_superchain_hardforks() {
+        let mut superchains = Registry::read_superchain_configs();
+        let superchain = &mut superchains.superchains[0];
+        let defaults = superchain.config.hardforks;
+        let chain = ChainConfig {
+            chain_id: 1_234_567,
+            superchain_time: defaults.granite_time,
+            ..Default::default()
+        };
+        superchain.chains = alloc::vec![chain];
+
+        let registry = Registry::from_parts(Vec::new(), superchains).unwrap();
+        let rollup = &registry.rollup_configs[&1_234_567];
+        assert_eq!(rollup.canyon_time, Some(0));
+        assert_eq!(rollup.granite_time, defaults.granite_time);
+        assert_eq!(rollup.holocene_time, defaults.holocene_time);
+    }
+
+    #[test]
+    fn test_from_dir() {
+        let superchain = Registry::read_superchain_configs()
+            .superchains
+            .into_iter()
+            .find(|s| s.name == "mainnet")
+            .unwrap();
+        let op_mainnet = superchain.chains.iter().find(|c| c.chain_id == 10).unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let configs = root.join("superchain").join("configs").join("mainnet");
+        std::fs::create_dir_all(&configs).unwrap();
+        std::fs::copy(
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("etc/chainList.json"),
+            root.join("chainList.json"),
+        )
+        .unwrap();
+        std::fs::write(configs.join("op.toml"), toml::to_string(op_mainnet).unwrap()).unwrap();
+
+        // A superchain without its `superchain.toml` is rejected.
+        assert!(matches!(
+            Registry::from_dir(root),
+            Err(RegistryError::MissingSuperchainConfig(n)) if n == "mainnet"
+        ));
+
+        std::fs::write(
+            configs.join("superchain.toml"),
+            toml::to_string(&superchain.config).unwrap(),
+        )
+        .unwrap();
+        let registry = Registry::from_dir(root).unwrap();
+
+        assert_eq!(registry.rollup_configs.len(), 1);
+        assert_eq!(registry.rollup_configs[&10], crate::test_utils::OP_MAINNET_CONFIG);
+        assert_eq!(registry.chains, Registry::read_chain_list());
+    }
+
+    #[test]
+    fn test_chain_lookups() {
+        let registry = Registry::from_chain_list();
+        let op = registry.find_chain("mainnet/op").unwrap();
+        assert_eq!(op.chain_id, 10);
+        assert_eq!(registry.find_chain("OP Mainnet"), Some(op));
+        assert_eq!(registry.chain_by_name("op mainnet"), Some(op));
+        assert_eq!(registry.chain(10), Some(op));
+        assert!(registry.find_chain("mainnet/unknown").is_none());
+    }
+
+    #[test]
+    fn test_chain_filters() {
+        let registry = Registry::from_chain_list();
+        assert!(registry
+            .chains_in_superchain("sepolia")
+            .all(|c| c.identifier.starts_with("sepolia/")));
+        assert!(registry.chains_in_superchain("mainnet").any(|c| c.chain_id == 8453));
+
+        let standard = registry.chains_by_level(SuperchainLevel::Standard).collect::<Vec<_>>();
+        assert!(standard.iter().any(|c| c.chain_id == 10));
+        assert!(standard.iter().all(|c| c.superchain_level == 2));
+
+        assert!(registry.chains_by_da_type("alt-da").all(|c| c.data_availability_type == "alt-da"));
+        assert!(registry.chains_by_da_type("eth-da").any(|c| c.chain_id == 10));
+    }
+
+    #[test]
+    fn test_chains_with_fork_active() {
+        let registry = Registry::from_chain_list();
+        let holocene = crate::test_utils::OP_MAINNET_CONFIG.holocene_time.unwrap();
+        let is_active = |ts| {
+            registry.chains_with_fork_active(OpHardfork::Holocene, ts).any(|c| c.chain_id == 10)
+        };
+        assert!(!is_active(holocene - 1));
+        assert!(is_active(holocene));
+    }
+
+    #[test]
+    fn test_upcoming_activations() {
+        let registry = Registry::from_chain_list();
+        let granite = crate::test_utils::OP_MAINNET_CONFIG.granite_time.unwrap();
+        let activations = registry.upcoming_activations(granite);
+        assert!(activations.is_sorted_by_key(|a| a.timestamp));
+        assert!(activations.iter().all(|a| a.timestamp > granite));
+        assert!(activations.contains(&HardforkActivation {
+            chain_id: 10,
+            fork: OpHardfork::Holocene,
+            timestamp: crate::test_utils::OP_MAINNET_CONFIG.holocene_time.unwrap(),
+        }));
+        assert!(!activations.iter().any(|a| a.chain_id == 10 && a.fork == OpHardfork::Granite));
+    }
+
+    #[test]
+    fn test_upcoming_activations_implied_forks() {
+        let mut registry = Registry::default();
+        registry.rollup_configs.insert(
+            10,
+            RollupConfig {
+                genesis: ChainGenesis { l2_time: 5, ..Default::default() },
+                regolith_time: Some(0),
+                holocene_time: Some(30),
+                ..Default::default()
+            },
+        );
+
+        // Hardforks configured before the genesis activate at the genesis.
+        assert!(registry.upcoming_activations(0).contains(&HardforkActivation {
+            chain_id: 10,
+            fork: OpHardfork::Regolith,
+            timestamp: 5,
+        }));
+
+        // The hardforks implied by Holocene activate with it.
+        let forks = registry.upcoming_activations(5).into_iter().map(|a| (a.fork, a.timestamp));
+        assert_eq!(
+            forks.collect::<Vec<_>>(),
+            [
+                (OpHardfork::Canyon, 30),
+                (OpHardfork::Delta, 30),
+                (OpHardfork::Ecotone, 30),
+                (OpHardfork::Fjord, 30),
+                (OpHardfork::Granite, 30),
+                (OpHardfork::Holocene, 30),
+            ]
+        );
+    }
+
     #[test]
     fn test_read_rollup_configs() {
         let superchains = Registry::from_chain_list();
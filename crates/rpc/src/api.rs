@@ -3,10 +3,14 @@
 use alloc::{boxed::Box, string::String, vec::Vec};
 use core::net::IpAddr;
 
-use alloy_eips::BlockNumberOrTag;
+use alloy_eips::{eip1898::BlockNumHash, BlockNumberOrTag};
+use alloy_primitives::{map::HashMap, B256, U64};
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
 use maili_genesis::RollupConfig;
-use maili_interop::{ExecutingMessage, SafetyLevel};
+use maili_interop::{
+    DerivedIdPair, ExecutingMessage, MessageIdentifier, SafetyLevel, SuperRootResponse,
+};
+use maili_protocol::BlockInfo;
 
 #[cfg_attr(all(target_arch = "wasm32", target_os = "unknown"), allow(unused_imports))]
 use getrandom as _; // required for compiling wasm32-unknown-unknown
@@ -145,9 +149,22 @@ pub trait EngineApiExt {
 }
 
 /// Supervisor API for interop.
+///
+/// Errors are reported with the codes of the `op-supervisor`, see
+/// [`SupervisorErrorCode`](crate::SupervisorErrorCode).
+///
+/// <https://github.com/ethereum-optimism/optimism/blob/develop/op-supervisor/supervisor/frontend/frontend.go>
 #[cfg_attr(not(feature = "client"), rpc(server, namespace = "supervisor"))]
 #[cfg_attr(feature = "client", rpc(server, client, namespace = "supervisor"))]
 pub trait SupervisorApi {
+    /// Returns the safety level of the message with the given identifier and payload hash.
+    #[method(name = "checkMessage")]
+    async fn check_message(
+        &self,
+        identifier: MessageIdentifier,
+        payload_hash: B256,
+    ) -> RpcResult<SafetyLevel>;
+
     /// Checks if the given messages meet the given minimum safety level.
     #[method(name = "checkMessages")]
     async fn check_messages(
@@ -155,4 +172,39 @@ pub trait SupervisorApi {
         messages: Vec<ExecutingMessage>,
         min_safety: SafetyLevel,
     ) -> RpcResult<()>;
+
+    /// Returns the L1 block the given cross-safe L2 block of the chain was derived from.
+    #[method(name = "crossDerivedFrom")]
+    async fn cross_derived_from(
+        &self,
+        chain_id: U64,
+        derived: BlockNumHash,
+    ) -> RpcResult<BlockInfo>;
+
+    /// Returns the local unsafe head of the chain.
+    #[method(name = "localUnsafe")]
+    async fn local_unsafe(&self, chain_id: U64) -> RpcResult<BlockNumHash>;
+
+    /// Returns the cross safe head of the chain, along with the L1 block it was derived from.
+    #[method(name = "crossSafe")]
+    async fn cross_safe(&self, chain_id: U64) -> RpcResult<DerivedIdPair>;
+
+    /// Returns the finalized head of the chain.
+    #[method(name = "finalized")]
+    async fn finalized(&self, chain_id: U64) -> RpcResult<BlockNumHash>;
+
+    /// Returns the finalized L1 block.
+    #[method(name = "finalizedL1")]
+    async fn finalized_l1(&self) -> RpcResult<BlockInfo>;
+
+    /// Returns the super root of the dependency set at the given timestamp.
+    #[method(name = "superRootAtTimestamp")]
+    async fn super_root_at_timestamp(&self, timestamp: U64) -> RpcResult<SuperRootResponse>;
+
+    /// Returns the latest safe L2 block of every chain, derived from the given L1 block.
+    #[method(name = "allSafeDerivedAt")]
+    async fn all_safe_derived_at(
+        &self,
+        derived_from: BlockNumHash,
+    ) -> RpcResult<HashMap<u64, BlockNumHash>>;
 }
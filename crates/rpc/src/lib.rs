@@ -24,11 +24,17 @@ pub use superchain::{
 };
 
 mod output;
-pub use output::OutputResponse;
+pub use output::{OutputResponse, OutputRoot, OutputRootError};
+
+mod super_root;
+pub use super_root::{SuperRootBuilder, SuperRootBuilderError};
 
 mod attributes;
 pub use attributes::OpAttributesWithParent;
 
+mod supervisor;
+pub use supervisor::SupervisorErrorCode;
+
 mod sync;
 pub use sync::{L2BlockRef, SyncStatus};
 
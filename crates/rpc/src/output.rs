@@ -1,7 +1,10 @@
 //! Output Types
 
 use crate::{L2BlockRef, SyncStatus};
-use alloy_primitives::B256;
+use alloy_consensus::Header;
+use alloy_primitives::{keccak256, B256};
+use derive_more::Display;
+use maili_protocol::{verify_withdrawal_proof, WithdrawalError, WithdrawalProof};
 
 /// An [output response][or] for Optimism Rollup.
 ///
@@ -23,3 +26,306 @@ pub struct OutputResponse {
     /// The status of the node sync.
     pub sync_status: SyncStatus,
 }
+
+/// An error returned when an [OutputResponse] does not match the [OutputRoot] it is checked
+/// against.
+#[derive(Copy, Clone, Debug, Display, PartialEq, Eq)]
+pub enum OutputRootError {
+    /// The output version is not supported.
+    #[display("Unsupported output version: {_0}")]
+    UnsupportedVersion(B256),
+    /// The state root does not match.
+    #[display("State root mismatch: expected {expected}, got {got}")]
+    StateRootMismatch {
+        /// The expected state root.
+        expected: B256,
+        /// The state root of the response.
+        got: B256,
+    },
+    /// The message passer storage root does not match.
+    #[display("Withdrawal storage root mismatch: expected {expected}, got {got}")]
+    StorageRootMismatch {
+        /// The expected storage root.
+        expected: B256,
+        /// The storage root of the response.
+        got: B256,
+    },
+    /// The block hash does not match.
+    #[display("Block hash mismatch: expected {expected}, got {got}")]
+    BlockHashMismatch {
+        /// The expected block hash.
+        expected: B256,
+        /// The block hash of the response.
+        got: B256,
+    },
+    /// The output root does not match.
+    #[display("Output root mismatch: expected {expected}, got {got}")]
+    OutputRootMismatch {
+        /// The expected output root.
+        expected: B256,
+        /// The output root of the response.
+        got: B256,
+    },
+}
+
+impl core::error::Error for OutputRootError {}
+
+/// A version 0 [output root][or], the commitment to the state of an L2 block that is proposed
+/// to L1.
+///
+/// [or]: https://specs.optimism.io/protocol/proposals.html#l2-output-commitment-construction
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct OutputRoot {
+    /// The state root of the L2 block.
+    pub state_root: B256,
+    /// The storage root of the `L2ToL1MessagePasser` predeploy.
+    pub message_passer_storage_root: B256,
+    /// The hash of the L2 block.
+    pub block_hash: B256,
+}
+
+impl OutputRoot {
+    /// The version of the output root.
+    pub const VERSION: B256 = B256::ZERO;
+
+    /// The length of the encoded output root preimage.
+    pub const ENCODED_LENGTH: usize = 128;
+
+    /// Creates a new [OutputRoot] from its parts.
+    pub const fn from_parts(
+        state_root: B256,
+        message_passer_storage_root: B256,
+        block_hash: B256,
+    ) -> Self {
+        Self { state_root, message_passer_storage_root, block_hash }
+    }
+
+    /// Creates a new [OutputRoot] from an L2 block header and the storage root of the
+    /// `L2ToL1MessagePasser` predeploy at that block.
+    ///
+    /// The storage root is typically read with `eth_getProof` for
+    /// [maili_protocol::Predeploys::L2_TO_L1_MESSAGE_PASSER].
+    pub fn from_header(header: &Header, message_passer_storage_root: B256) -> Self {
+        Self::from_parts(header.state_root, message_passer_storage_root, header.hash_slow())
+    }
+
+    /// Encodes the output root preimage.
+    pub fn encode(&self) -> [u8; Self::ENCODED_LENGTH] {
+        let mut buf = [0u8; Self::ENCODED_LENGTH];
+        buf[..32].copy_from_slice(Self::VERSION.as_slice());
+        buf[32..64].copy_from_slice(self.state_root.as_slice());
+        buf[64..96].copy_from_slice(self.message_passer_storage_root.as_slice());
+        buf[96..].copy_from_slice(self.block_hash.as_slice());
+        buf
+    }
+
+    /// Hashes the encoded [OutputRoot] using [keccak256].
+    pub fn hash(&self) -> B256 {
+        keccak256(self.encode())
+    }
+
+    /// Verifies that the [OutputResponse] commits to this output root.
+    ///
+    /// The version, each component and finally the output root of the response are checked, and
+    /// the first mismatch is returned.
+    pub fn verify(&self, response: &OutputResponse) -> Result<(), OutputRootError> {
+        if response.version != Self::VERSION {
+            return Err(OutputRootError::UnsupportedVersion(response.version));
+        }
+        if response.state_root != self.state_root {
+            return Err(OutputRootError::StateRootMismatch {
+                expected: self.state_root,
+                got: response.state_root,
+            });
+        }
+        if response.withdrawal_storage_root != self.message_passer_storage_root {
+            return Err(OutputRootError::StorageRootMismatch {
+                expected: self.message_passer_storage_root,
+                got: response.withdrawal_storage_root,
+            });
+        }
+        let block_hash = response.block_ref.l1_block_info.hash;
+        if block_hash != self.block_hash {
+            return Err(OutputRootError::BlockHashMismatch {
+                expected: self.block_hash,
+                got: block_hash,
+            });
+        }
+        let output_root = self.hash();
+        if response.output_root != output_root {
+            return Err(OutputRootError::OutputRootMismatch {
+                expected: output_root,
+                got: response.output_root,
+            });
+        }
+        Ok(())
+    }
+
+    /// Verifies that the withdrawal with the given hash was sent in the L2 block committed to by
+    /// this output root.
+    ///
+    /// The proof is the `eth_getProof` response of the `L2ToL1MessagePasser` for the
+    /// `sentMessages` slot of the withdrawal, at the L2 block of the output.
+    pub fn verify_withdrawal(
+        &self,
+        withdrawal_hash: B256,
+        proof: &WithdrawalProof,
+    ) -> Result<(), WithdrawalError> {
+        verify_withdrawal_proof(
+            withdrawal_hash,
+            self.state_root,
+            self.message_passer_storage_root,
+            proof,
+        )
+    }
+}
+
+impl From<&OutputResponse> for OutputRoot {
+    fn from(response: &OutputResponse) -> Self {
+        Self::from_parts(
+            response.state_root,
+            response.withdrawal_storage_root,
+            response.block_ref.l1_block_info.hash,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_eips::BlockNumHash;
+    use alloy_primitives::b256;
+    use maili_protocol::BlockInfo;
+
+    const STATE_ROOT: B256 =
+        b256!("0101010101010101010101010101010101010101010101010101010101010101");
+    const STORAGE_ROOT: B256 =
+        b256!("0202020202020202020202020202020202020202020202020202020202020202");
+    const BLOCK_HASH: B256 =
+        b256!("0303030303030303030303030303030303030303030303030303030303030303");
+
+    fn block_ref(hash: B256) -> L2BlockRef {
+        L2BlockRef {
+            l1_block_info: BlockInfo { hash, ..Default::default() },
+            l1_origin: BlockNumHash::default(),
+            sequence_number: 0,
+        }
+    }
+
+    fn response(root: &OutputRoot) -> OutputResponse {
+        let block_ref = block_ref(root.block_hash);
+        OutputResponse {
+            version: OutputRoot::VERSION,
+            output_root: root.hash(),
+            block_ref,
+            withdrawal_storage_root: root.message_passer_storage_root,
+            state_root: root.state_root,
+            sync_status: SyncStatus {
+                current_l1: BlockInfo::default(),
+                current_l1_finalized: BlockInfo::default(),
+                head_l1: BlockInfo::default(),
+                safe_l1: BlockInfo::default(),
+                finalized_l1: BlockInfo::default(),
+                unsafe_l2: block_ref,
+                safe_l2: block_ref,
+                finalized_l2: block_ref,
+                pending_safe_l2: block_ref,
+                cross_unsafe_l2: block_ref,
+                local_safe_l2: block_ref,
+            },
+        }
+    }
+
+    #[test]
+    fn test_output_root_hash() {
+        let root = OutputRoot::from_parts(STATE_ROOT, STORAGE_ROOT, BLOCK_HASH);
+        let preimage =
+            [B256::ZERO.as_slice(), STATE_ROOT.as_slice(), STORAGE_ROOT.as_slice(), &BLOCK_HASH.0]
+                .concat();
+        assert_eq!(root.encode().as_slice(), preimage.as_slice());
+        assert_eq!(root.hash(), keccak256(&preimage));
+    }
+
+    #[test]
+    fn test_output_root_from_header() {
+        let header = Header { state_root: STATE_ROOT, number: 1, ..Default::default() };
+        let root = OutputRoot::from_header(&header, STORAGE_ROOT);
+        assert_eq!(root.state_root, STATE_ROOT);
+        assert_eq!(root.message_passer_storage_root, STORAGE_ROOT);
+        assert_eq!(root.block_hash, header.hash_slow());
+    }
+
+    #[test]
+    fn test_output_root_from_response() {
+        let root = OutputRoot::from_parts(STATE_ROOT, STORAGE_ROOT, BLOCK_HASH);
+        assert_eq!(OutputRoot::from(&response(&root)), root);
+    }
+
+    #[test]
+    fn test_verify_output_response() {
+        let root = OutputRoot::from_parts(STATE_ROOT, STORAGE_ROOT, BLOCK_HASH);
+        assert_eq!(root.verify(&response(&root)), Ok(()));
+    }
+
+    #[test]
+    fn test_verify_unsupported_version() {
+        let root = OutputRoot::from_parts(STATE_ROOT, STORAGE_ROOT, BLOCK_HASH);
+        let mut response = response(&root);
+        response.version = B256::with_last_byte(1);
+        assert_eq!(
+            root.verify(&response),
+            Err(OutputRootError::UnsupportedVersion(B256::with_last_byte(1)))
+        );
+    }
+
+    #[test]
+    fn test_verify_component_mismatch() {
+        let root = OutputRoot::from_parts(STATE_ROOT, STORAGE_ROOT, BLOCK_HASH);
+
+        let mut bad = response(&root);
+        bad.state_root = B256::ZERO;
+        assert_eq!(
+            root.verify(&bad),
+            Err(OutputRootError::StateRootMismatch { expected: STATE_ROOT, got: B256::ZERO })
+        );
+
+        let mut bad = response(&root);
+        bad.withdrawal_storage_root = B256::ZERO;
+        assert_eq!(
+            root.verify(&bad),
+            Err(OutputRootError::StorageRootMismatch { expected: STORAGE_ROOT, got: B256::ZERO })
+        );
+
+        let mut bad = response(&root);
+        bad.block_ref = block_ref(B256::ZERO);
+        assert_eq!(
+            root.verify(&bad),
+            Err(OutputRootError::BlockHashMismatch { expected: BLOCK_HASH, got: B256::ZERO })
+        );
+    }
+
+    #[test]
+    fn test_verify_withdrawal_storage_root_mismatch() {
+        let root = OutputRoot::from_parts(STATE_ROOT, STORAGE_ROOT, BLOCK_HASH);
+        let proof = WithdrawalProof {
+            account: Default::default(),
+            account_proof: Default::default(),
+            storage_proof: Default::default(),
+        };
+        assert!(matches!(
+            root.verify_withdrawal(B256::ZERO, &proof),
+            Err(WithdrawalError::StorageRootMismatch { expected: STORAGE_ROOT, .. })
+        ));
+    }
+
+    #[test]
+    fn test_verify_output_root_mismatch() {
+        let root = OutputRoot::from_parts(STATE_ROOT, STORAGE_ROOT, BLOCK_HASH);
+        let mut response = response(&root);
+        response.output_root = B256::ZERO;
+        assert_eq!(
+            root.verify(&response),
+            Err(OutputRootError::OutputRootMismatch { expected: root.hash(), got: B256::ZERO })
+        );
+    }
+}
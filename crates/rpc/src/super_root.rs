@@ -0,0 +1,199 @@
+//! Contains the [SuperRootBuilder], building a [SuperRoot] from the output roots of each chain.
+
+use crate::{OutputResponse, OutputRoot, OutputRootError};
+use alloc::vec::Vec;
+use derive_more::Display;
+use maili_interop::{ChainRootInfo, OutputRootWithChain, SuperRoot};
+
+/// An error building a [SuperRoot] with the [SuperRootBuilder].
+#[derive(Copy, Clone, Debug, Display, PartialEq, Eq)]
+pub enum SuperRootBuilderError {
+    /// More than one output was provided for the chain.
+    #[display("Duplicate output for chain {_0}")]
+    DuplicateChain(u64),
+    /// The block of the chain output is after the super root timestamp.
+    #[display("Block of chain {chain_id} at {block_timestamp} is after the super root timestamp {timestamp}")]
+    BlockAfterTimestamp {
+        /// The chain ID.
+        chain_id: u64,
+        /// The timestamp of the output block.
+        block_timestamp: u64,
+        /// The super root timestamp.
+        timestamp: u64,
+    },
+    /// The output of the chain does not commit to its output root.
+    #[display("Invalid output for chain {chain_id}: {error}")]
+    InvalidOutput {
+        /// The chain ID.
+        chain_id: u64,
+        /// The output root error.
+        error: OutputRootError,
+    },
+}
+
+impl core::error::Error for SuperRootBuilderError {}
+
+/// A builder of the [SuperRoot] at a timestamp, from the [OutputResponse] of each chain of the
+/// dependency set at or before that timestamp.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SuperRootBuilder {
+    /// The timestamp of the super root.
+    timestamp: u64,
+    /// The outputs of each chain, by chain ID.
+    outputs: Vec<(u64, OutputResponse)>,
+}
+
+impl SuperRootBuilder {
+    /// Creates a new [SuperRootBuilder] for the super root at the given timestamp.
+    pub const fn new(timestamp: u64) -> Self {
+        Self { timestamp, outputs: Vec::new() }
+    }
+
+    /// Adds the output of the given chain.
+    pub fn with_output(mut self, chain_id: u64, output: OutputResponse) -> Self {
+        self.outputs.push((chain_id, output));
+        self
+    }
+
+    /// Builds the [SuperRoot], along with the [ChainRootInfo] of each chain ordered by chain ID.
+    ///
+    /// Every output is verified to commit to its output root, with its block at or before the
+    /// super root timestamp.
+    pub fn build(mut self) -> Result<(SuperRoot, Vec<ChainRootInfo>), SuperRootBuilderError> {
+        self.outputs.sort_by_key(|(chain_id, _)| *chain_id);
+        if let Some(pair) = self.outputs.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+            return Err(SuperRootBuilderError::DuplicateChain(pair[0].0));
+        }
+
+        let mut output_roots = Vec::with_capacity(self.outputs.len());
+        let mut chains = Vec::with_capacity(self.outputs.len());
+        for (chain_id, output) in &self.outputs {
+            let chain_id = *chain_id;
+            let block_timestamp = output.block_ref.l1_block_info.timestamp;
+            if block_timestamp > self.timestamp {
+                return Err(SuperRootBuilderError::BlockAfterTimestamp {
+                    chain_id,
+                    block_timestamp,
+                    timestamp: self.timestamp,
+                });
+            }
+
+            let root = OutputRoot::from(output);
+            root.verify(output)
+                .map_err(|error| SuperRootBuilderError::InvalidOutput { chain_id, error })?;
+            output_roots.push(OutputRootWithChain::new(chain_id, output.output_root));
+            chains.push(ChainRootInfo {
+                chain_id,
+                canonical: output.output_root,
+                pending: root.encode().to_vec().into(),
+            });
+        }
+
+        Ok((SuperRoot::new(self.timestamp, output_roots), chains))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{L2BlockRef, SyncStatus};
+    use alloc::vec;
+    use alloy_eips::BlockNumHash;
+    use alloy_primitives::B256;
+    use maili_protocol::BlockInfo;
+
+    fn output(seed: u8, timestamp: u64) -> OutputResponse {
+        let root = OutputRoot::from_parts(
+            B256::with_last_byte(seed),
+            B256::with_last_byte(seed + 1),
+            B256::with_last_byte(seed + 2),
+        );
+        let block_ref = L2BlockRef {
+            l1_block_info: BlockInfo { hash: root.block_hash, timestamp, ..Default::default() },
+            l1_origin: BlockNumHash::default(),
+            sequence_number: 0,
+        };
+        OutputResponse {
+            version: OutputRoot::VERSION,
+            output_root: root.hash(),
+            block_ref,
+            withdrawal_storage_root: root.message_passer_storage_root,
+            state_root: root.state_root,
+            sync_status: SyncStatus {
+                current_l1: BlockInfo::default(),
+                current_l1_finalized: BlockInfo::default(),
+                head_l1: BlockInfo::default(),
+                safe_l1: BlockInfo::default(),
+                finalized_l1: BlockInfo::default(),
+                unsafe_l2: block_ref,
+                safe_l2: block_ref,
+                finalized_l2: block_ref,
+                pending_safe_l2: block_ref,
+                cross_unsafe_l2: block_ref,
+                local_safe_l2: block_ref,
+            },
+        }
+    }
+
+    #[test]
+    fn test_super_root_builder() {
+        let (op, base) = (output(1, 10), output(10, 9));
+        let (super_root, chains) = SuperRootBuilder::new(10)
+            .with_output(8453, base.clone())
+            .with_output(10, op.clone())
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            super_root,
+            SuperRoot::new(
+                10,
+                vec![
+                    OutputRootWithChain::new(10, op.output_root),
+                    OutputRootWithChain::new(8453, base.output_root),
+                ]
+            )
+        );
+        assert_eq!(chains.len(), 2);
+        assert_eq!(chains[1].chain_id, 8453);
+        assert_eq!(chains[1].canonical, base.output_root);
+        assert_eq!(chains[1].pending.as_ref(), OutputRoot::from(&base).encode());
+    }
+
+    #[test]
+    fn test_super_root_builder_block_after_timestamp() {
+        let err = SuperRootBuilder::new(10).with_output(10, output(1, 11)).build().unwrap_err();
+        assert_eq!(
+            err,
+            SuperRootBuilderError::BlockAfterTimestamp {
+                chain_id: 10,
+                block_timestamp: 11,
+                timestamp: 10
+            }
+        );
+    }
+
+    #[test]
+    fn test_super_root_builder_invalid_output() {
+        let mut invalid = output(1, 10);
+        invalid.output_root = B256::ZERO;
+        let err = SuperRootBuilder::new(10).with_output(10, invalid).build().unwrap_err();
+        assert!(matches!(
+            err,
+            SuperRootBuilderError::InvalidOutput {
+                chain_id: 10,
+                error: OutputRootError::OutputRootMismatch { .. }
+            }
+        ));
+    }
+
+    #[test]
+    fn test_super_root_builder_duplicate_chain() {
+        let err = SuperRootBuilder::new(10)
+            .with_output(10, output(1, 10))
+            .with_output(10, output(2, 10))
+            .build()
+            .unwrap_err();
+        assert_eq!(err, SuperRootBuilderError::DuplicateChain(10));
+    }
+}
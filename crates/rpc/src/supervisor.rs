@@ -0,0 +1,354 @@
+//! Error codes returned by the `op-supervisor` RPC.
+
+/// An error code returned by the `supervisor_*` RPC methods.
+///
+/// Mirrors the error codes of the `op-supervisor` service, so that clients of the
+/// [`SupervisorApiServer`](crate::SupervisorApiServer) can match on errors the same way they
+/// would against a real supervisor.
+///
+/// <https://github.com/ethereum-optimism/optimism/blob/develop/op-supervisor/supervisor/types/error.go>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, derive_more::Display)]
+pub enum SupervisorErrorCode {
+    /// The chain database has not been initialized yet.
+    #[display("uninitialized chain database")]
+    UninitializedChainDatabase,
+    /// Data was skipped when appending to a chain database.
+    #[display("skipped data")]
+    SkippedData,
+    /// The chain is not known to the supervisor.
+    #[display("unknown chain")]
+    UnknownChain,
+    /// The data conflicts with data already known to the supervisor.
+    #[display("conflicting data")]
+    ConflictingData,
+    /// The data is already known to the supervisor.
+    #[display("ineffective data")]
+    IneffectiveData,
+    /// The data was provided out of order.
+    #[display("out of order")]
+    OutOfOrder,
+    /// The supervisor is waiting for a replacement block.
+    #[display("awaiting replacement block")]
+    AwaitingReplacementBlock,
+    /// The iteration was stopped.
+    #[display("iter stop")]
+    IterStop,
+    /// The data is out of the scope of the query.
+    #[display("out of scope")]
+    OutOfScope,
+    /// The parent of the first block in the database cannot be retrieved.
+    #[display("cannot get parent of first block in the database")]
+    CannotGetParentOfFirstBlockInDb,
+    /// The data is not yet known to the supervisor.
+    #[display("future data")]
+    FutureData,
+    /// The data was missed by the supervisor.
+    #[display("missed data")]
+    MissedData,
+    /// The supervisor database is corrupted.
+    #[display("data corruption")]
+    DataCorruption,
+}
+
+impl SupervisorErrorCode {
+    /// Returns the JSON-RPC error code of the [SupervisorErrorCode].
+    pub const fn code(&self) -> i32 {
+        match self {
+            Self::UninitializedChainDatabase => -320400,
+            Self::SkippedData => -320500,
+            Self::UnknownChain => -320501,
+            Self::ConflictingData => -320600,
+            Self::IneffectiveData => -320601,
+            Self::OutOfOrder => -320900,
+            Self::AwaitingReplacementBlock => -320901,
+            Self::IterStop => -321000,
+            Self::OutOfScope => -321100,
+            Self::CannotGetParentOfFirstBlockInDb => -321200,
+            Self::FutureData => -321401,
+            Self::MissedData => -321500,
+            Self::DataCorruption => -321501,
+        }
+    }
+
+    /// Returns the [SupervisorErrorCode] of the given JSON-RPC error code, if it is one returned
+    /// by the `op-supervisor`.
+    pub const fn from_code(code: i32) -> Option<Self> {
+        Some(match code {
+            -320400 => Self::UninitializedChainDatabase,
+            -320500 => Self::SkippedData,
+            -320501 => Self::UnknownChain,
+            -320600 => Self::ConflictingData,
+            -320601 => Self::IneffectiveData,
+            -320900 => Self::OutOfOrder,
+            -320901 => Self::AwaitingReplacementBlock,
+            -321000 => Self::IterStop,
+            -321100 => Self::OutOfScope,
+            -321200 => Self::CannotGetParentOfFirstBlockInDb,
+            -321401 => Self::FutureData,
+            -321500 => Self::MissedData,
+            -321501 => Self::DataCorruption,
+            _ => return None,
+        })
+    }
+}
+
+#[cfg(feature = "jsonrpsee")]
+impl From<SupervisorErrorCode> for jsonrpsee::types::ErrorObjectOwned {
+    fn from(code: SupervisorErrorCode) -> Self {
+        Self::owned(code.code(), alloc::string::ToString::to_string(&code), None::<()>)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CODES: [SupervisorErrorCode; 13] = [
+        SupervisorErrorCode::UninitializedChainDatabase,
+        SupervisorErrorCode::SkippedData,
+        SupervisorErrorCode::UnknownChain,
+        SupervisorErrorCode::ConflictingData,
+        SupervisorErrorCode::IneffectiveData,
+        SupervisorErrorCode::OutOfOrder,
+        SupervisorErrorCode::AwaitingReplacementBlock,
+        SupervisorErrorCode::IterStop,
+        SupervisorErrorCode::OutOfScope,
+        SupervisorErrorCode::CannotGetParentOfFirstBlockInDb,
+        SupervisorErrorCode::FutureData,
+        SupervisorErrorCode::MissedData,
+        SupervisorErrorCode::DataCorruption,
+    ];
+
+    #[test]
+    fn test_supervisor_error_code_roundtrip() {
+        for code in CODES {
+            assert_eq!(SupervisorErrorCode::from_code(code.code()), Some(code));
+        }
+        assert_eq!(SupervisorErrorCode::from_code(-32000), None);
+    }
+
+    #[test]
+    fn test_supervisor_error_codes() {
+        assert_eq!(SupervisorErrorCode::ConflictingData.code(), -320600);
+        assert_eq!(SupervisorErrorCode::UnknownChain.code(), -320501);
+        assert_eq!(SupervisorErrorCode::FutureData.code(), -321401);
+    }
+
+    #[test]
+    #[cfg(feature = "jsonrpsee")]
+    fn test_supervisor_error_code_to_error_object() {
+        let err = jsonrpsee::types::ErrorObjectOwned::from(SupervisorErrorCode::ConflictingData);
+        assert_eq!(err.code(), -320600);
+        assert_eq!(err.message(), "conflicting data");
+    }
+
+    #[cfg(feature = "client")]
+    mod mock {
+        use super::*;
+        use crate::{SupervisorApiClient, SupervisorApiServer};
+        use alloc::{boxed::Box, format, vec, vec::Vec};
+        use alloy_eips::eip1898::BlockNumHash;
+        use alloy_primitives::{b256, map::HashMap, Bytes, B256, U64};
+        use jsonrpsee::{
+            core::{async_trait, client::Error as ClientError, RpcResult},
+            http_client::{HttpClient, HttpClientBuilder},
+            server::{Server, ServerHandle},
+        };
+        use maili_interop::{
+            ChainRootInfo, DerivedIdPair, ExecutingMessage, MessageIdentifier, SafetyLevel,
+            SuperRootResponse,
+        };
+        use maili_protocol::BlockInfo;
+
+        const HASH: B256 =
+            b256!("0000000000000000000000000000000000000000000000000000000000000001");
+
+        /// A mock supervisor that knows of chains 10 and 11.
+        struct MockSupervisor;
+
+        impl MockSupervisor {
+            fn chain(chain_id: U64) -> RpcResult<u64> {
+                let chain_id = chain_id.to::<u64>();
+                if chain_id != 10 && chain_id != 11 {
+                    return Err(SupervisorErrorCode::UnknownChain.into());
+                }
+                Ok(chain_id)
+            }
+        }
+
+        #[async_trait]
+        impl SupervisorApiServer for MockSupervisor {
+            async fn check_message(
+                &self,
+                _identifier: MessageIdentifier,
+                _payload_hash: B256,
+            ) -> RpcResult<SafetyLevel> {
+                Ok(SafetyLevel::CrossUnsafe)
+            }
+
+            async fn check_messages(
+                &self,
+                _messages: Vec<ExecutingMessage>,
+                min_safety: SafetyLevel,
+            ) -> RpcResult<()> {
+                if min_safety == SafetyLevel::Finalized {
+                    return Err(SupervisorErrorCode::ConflictingData.into());
+                }
+                Ok(())
+            }
+
+            async fn cross_derived_from(
+                &self,
+                chain_id: U64,
+                derived: BlockNumHash,
+            ) -> RpcResult<BlockInfo> {
+                Ok(BlockInfo {
+                    hash: derived.hash,
+                    number: derived.number + Self::chain(chain_id)?,
+                    parent_hash: B256::ZERO,
+                    timestamp: 100,
+                })
+            }
+
+            async fn local_unsafe(&self, chain_id: U64) -> RpcResult<BlockNumHash> {
+                Ok(BlockNumHash::new(Self::chain(chain_id)?, HASH))
+            }
+
+            async fn cross_safe(&self, chain_id: U64) -> RpcResult<DerivedIdPair> {
+                Ok(DerivedIdPair {
+                    derived_from: BlockNumHash::new(1, HASH),
+                    derived: BlockNumHash::new(Self::chain(chain_id)?, HASH),
+                })
+            }
+
+            async fn finalized(&self, chain_id: U64) -> RpcResult<BlockNumHash> {
+                Ok(BlockNumHash::new(Self::chain(chain_id)?, HASH))
+            }
+
+            async fn finalized_l1(&self) -> RpcResult<BlockInfo> {
+                Ok(BlockInfo { hash: HASH, number: 1, ..Default::default() })
+            }
+
+            async fn super_root_at_timestamp(
+                &self,
+                timestamp: U64,
+            ) -> RpcResult<SuperRootResponse> {
+                if timestamp > U64::from(1_000) {
+                    return Err(SupervisorErrorCode::FutureData.into());
+                }
+                Ok(SuperRootResponse {
+                    timestamp: timestamp.to(),
+                    super_root: HASH,
+                    chains: vec![ChainRootInfo {
+                        chain_id: 10,
+                        canonical: HASH,
+                        pending: Bytes::from_static(&[0xFF]),
+                    }],
+                })
+            }
+
+            async fn all_safe_derived_at(
+                &self,
+                derived_from: BlockNumHash,
+            ) -> RpcResult<HashMap<u64, BlockNumHash>> {
+                Ok(HashMap::from_iter([
+                    (10, BlockNumHash::new(derived_from.number, HASH)),
+                    (11, BlockNumHash::new(derived_from.number + 1, HASH)),
+                ]))
+            }
+        }
+
+        /// Starts the [MockSupervisor] on a random local port and returns a client connected
+        /// to it.
+        async fn mock_supervisor() -> (HttpClient, ServerHandle) {
+            let server = Server::builder().build("127.0.0.1:0").await.unwrap();
+            let addr = server.local_addr().unwrap();
+            let handle = server.start(MockSupervisor.into_rpc());
+            let client = HttpClientBuilder::default().build(format!("http://{addr}")).unwrap();
+            (client, handle)
+        }
+
+        fn error_code(err: ClientError) -> Option<SupervisorErrorCode> {
+            let ClientError::Call(err) = err else {
+                panic!("expected a call error, got {err:?}");
+            };
+            SupervisorErrorCode::from_code(err.code())
+        }
+
+        #[tokio::test]
+        async fn test_check_message() {
+            let (client, _handle) = mock_supervisor().await;
+            let level = client.check_message(MessageIdentifier::default(), HASH).await.unwrap();
+            assert_eq!(level, SafetyLevel::CrossUnsafe);
+        }
+
+        #[tokio::test]
+        async fn test_check_messages() {
+            let (client, _handle) = mock_supervisor().await;
+            let messages = vec![ExecutingMessage::default()];
+            client.check_messages(messages.clone(), SafetyLevel::Safe).await.unwrap();
+
+            let err = client.check_messages(messages, SafetyLevel::Finalized).await.unwrap_err();
+            assert_eq!(error_code(err), Some(SupervisorErrorCode::ConflictingData));
+        }
+
+        #[tokio::test]
+        async fn test_cross_derived_from() {
+            let (client, _handle) = mock_supervisor().await;
+            let block =
+                client.cross_derived_from(U64::from(10), BlockNumHash::new(5, HASH)).await.unwrap();
+            assert_eq!(
+                block,
+                BlockInfo { hash: HASH, number: 15, parent_hash: B256::ZERO, timestamp: 100 }
+            );
+        }
+
+        #[tokio::test]
+        async fn test_chain_heads() {
+            let (client, _handle) = mock_supervisor().await;
+            let chain_id = U64::from(10);
+            assert_eq!(client.local_unsafe(chain_id).await.unwrap(), BlockNumHash::new(10, HASH));
+            assert_eq!(client.finalized(chain_id).await.unwrap(), BlockNumHash::new(10, HASH));
+
+            let pair = client.cross_safe(chain_id).await.unwrap();
+            assert_eq!(pair.derived_from, BlockNumHash::new(1, HASH));
+            assert_eq!(pair.derived, BlockNumHash::new(10, HASH));
+        }
+
+        #[tokio::test]
+        async fn test_unknown_chain() {
+            let (client, _handle) = mock_supervisor().await;
+            let err = client.local_unsafe(U64::from(12)).await.unwrap_err();
+            assert_eq!(error_code(err), Some(SupervisorErrorCode::UnknownChain));
+        }
+
+        #[tokio::test]
+        async fn test_finalized_l1() {
+            let (client, _handle) = mock_supervisor().await;
+            let block = client.finalized_l1().await.unwrap();
+            assert_eq!(block, BlockInfo { hash: HASH, number: 1, ..Default::default() });
+        }
+
+        #[tokio::test]
+        async fn test_super_root_at_timestamp() {
+            let (client, _handle) = mock_supervisor().await;
+            let response = client.super_root_at_timestamp(U64::from(42)).await.unwrap();
+            assert_eq!(response.timestamp, 42);
+            assert_eq!(response.super_root, HASH);
+            assert_eq!(response.chains.len(), 1);
+            assert_eq!(response.chains[0].chain_id, 10);
+
+            let err = client.super_root_at_timestamp(U64::from(1_001)).await.unwrap_err();
+            assert_eq!(error_code(err), Some(SupervisorErrorCode::FutureData));
+        }
+
+        #[tokio::test]
+        async fn test_all_safe_derived_at() {
+            let (client, _handle) = mock_supervisor().await;
+            let derived = client.all_safe_derived_at(BlockNumHash::new(5, HASH)).await.unwrap();
+            assert_eq!(derived.len(), 2);
+            assert_eq!(derived[&10], BlockNumHash::new(5, HASH));
+            assert_eq!(derived[&11], BlockNumHash::new(6, HASH));
+        }
+    }
+}
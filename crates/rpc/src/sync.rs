@@ -1,12 +1,13 @@
 //! Common sync types
 
 use alloy_eips::BlockNumHash;
+use maili_interop::SafetyLevel;
 use maili_protocol::BlockInfo;
 
 /// The block reference for an L2 block.
 ///
 /// See: <https://github.com/ethereum-optimism/optimism/blob/develop/op-service/eth/id.go#L33>
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct L2BlockRef {
@@ -44,4 +45,93 @@ pub struct SyncStatus {
     pub finalized_l2: L2BlockRef,
     /// The pending safe L2 block ref.
     pub pending_safe_l2: L2BlockRef,
+    /// The cross unsafe L2 block ref.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub cross_unsafe_l2: L2BlockRef,
+    /// The local safe L2 block ref.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub local_safe_l2: L2BlockRef,
+}
+
+impl SyncStatus {
+    /// Returns the L2 head matching the given [SafetyLevel], or [None] for
+    /// [SafetyLevel::Invalid].
+    pub const fn l2_head(&self, level: SafetyLevel) -> Option<&L2BlockRef> {
+        match level {
+            SafetyLevel::Finalized => Some(&self.finalized_l2),
+            SafetyLevel::Safe => Some(&self.safe_l2),
+            SafetyLevel::LocalSafe => Some(&self.local_safe_l2),
+            SafetyLevel::CrossUnsafe => Some(&self.cross_unsafe_l2),
+            SafetyLevel::Unsafe => Some(&self.unsafe_l2),
+            SafetyLevel::Invalid => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+
+    fn block_ref(number: u64) -> L2BlockRef {
+        L2BlockRef {
+            l1_block_info: BlockInfo {
+                number,
+                hash: B256::with_last_byte(number as u8),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_sync_status_l2_head() {
+        let status = SyncStatus {
+            current_l1: BlockInfo::default(),
+            current_l1_finalized: BlockInfo::default(),
+            head_l1: BlockInfo::default(),
+            safe_l1: BlockInfo::default(),
+            finalized_l1: BlockInfo::default(),
+            unsafe_l2: block_ref(5),
+            cross_unsafe_l2: block_ref(4),
+            local_safe_l2: block_ref(3),
+            safe_l2: block_ref(2),
+            finalized_l2: block_ref(1),
+            pending_safe_l2: block_ref(3),
+        };
+
+        // Safer levels map to lower heads.
+        let mut level = SafetyLevel::Unsafe;
+        let mut number = 5;
+        loop {
+            assert_eq!(status.l2_head(level).unwrap().l1_block_info.number, number);
+            let Some(next) = level.promote() else { break };
+            level = next;
+            number -= 1;
+        }
+        assert_eq!(status.l2_head(SafetyLevel::Invalid), None);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_sync_status_serde_without_interop_heads() {
+        let status = SyncStatus {
+            current_l1: BlockInfo::default(),
+            current_l1_finalized: BlockInfo::default(),
+            head_l1: BlockInfo::default(),
+            safe_l1: BlockInfo::default(),
+            finalized_l1: BlockInfo::default(),
+            unsafe_l2: block_ref(5),
+            cross_unsafe_l2: L2BlockRef::default(),
+            local_safe_l2: L2BlockRef::default(),
+            safe_l2: block_ref(2),
+            finalized_l2: block_ref(1),
+            pending_safe_l2: block_ref(3),
+        };
+        let mut json = serde_json::to_value(&status).unwrap();
+        let fields = json.as_object_mut().unwrap();
+        fields.remove("cross_unsafe_l2").unwrap();
+        fields.remove("local_safe_l2").unwrap();
+        assert_eq!(serde_json::from_value::<SyncStatus>(json).unwrap(), status);
+    }
 }